@@ -0,0 +1,178 @@
+use core::iter::FusedIterator;
+
+/// An iterator over every `K`-combination of the indices `0..N`, in lexicographic order.
+/// 
+/// Each combination is yielded as a sorted array of distinct indices, so no allocation is needed.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use array__ops::Combinations;
+/// 
+/// let c: Vec<[usize; 2]> = Combinations::<4, 2>::new().collect();
+/// 
+/// assert_eq!(c, [[0, 1], [0, 2], [0, 3], [1, 2], [1, 3], [2, 3]]);
+/// 
+/// let a = ['a', 'b', 'c'];
+/// let c: Vec<[char; 2]> = Combinations::<3, 2>::new()
+///     .map(|c| c.map(|i| a[i]))
+///     .collect();
+/// 
+/// assert_eq!(c, [['a', 'b'], ['a', 'c'], ['b', 'c']]);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct Combinations<const N: usize, const K: usize>
+{
+    next: Option<[usize; K]>
+}
+
+impl<const N: usize, const K: usize> Combinations<N, K>
+{
+    /// Creates an iterator starting at the first combination, `[0, 1, ..., K - 1]`.
+    /// 
+    /// If `K > N`, the iterator is empty.
+    pub const fn new() -> Self
+    {
+        if K > N
+        {
+            return Self {
+                next: None
+            }
+        }
+        let mut first = [0; K];
+        let mut i = 0;
+        while i < K
+        {
+            first[i] = i;
+            i += 1;
+        }
+        Self {
+            next: Some(first)
+        }
+    }
+
+    /// Counts the number of combinations left, or returns `None` if it overflows.
+    const fn remaining(&self) -> Option<usize>
+    {
+        let c = match &self.next
+        {
+            Some(c) => c,
+            None => return Some(0)
+        };
+        // The number of combinations following `c` is the sum of binomial(N - 1 - c[i], K - i)
+        let mut count = 1usize;
+        let mut i = 0;
+        while i < K
+        {
+            count = match r#impl::binomial(N - 1 - c[i], K - i)
+            {
+                Some(b) => match count.checked_add(b)
+                {
+                    Some(count) => count,
+                    None => return None
+                },
+                None => return None
+            };
+            i += 1;
+        }
+        Some(count)
+    }
+}
+
+impl<const N: usize, const K: usize> Default for Combinations<N, K>
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl<const N: usize, const K: usize> Iterator for Combinations<N, K>
+{
+    type Item = [usize; K];
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        let current = self.next?;
+
+        // Find the rightmost index that can still be incremented
+        let mut c = current;
+        let mut i = K;
+        while i > 0 && c[i - 1] == N - K + i - 1
+        {
+            i -= 1;
+        }
+        self.next = if i == 0
+        {
+            None
+        }
+        else
+        {
+            c[i - 1] += 1;
+            let mut j = i;
+            while j < K
+            {
+                c[j] = c[j - 1] + 1;
+                j += 1;
+            }
+            Some(c)
+        };
+
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        match self.remaining()
+        {
+            Some(n) => (n, Some(n)),
+            None => (usize::MAX, None)
+        }
+    }
+}
+impl<const N: usize, const K: usize> FusedIterator for Combinations<N, K> {}
+
+mod r#impl
+{
+    pub(super) const fn binomial(n: usize, k: usize) -> Option<usize>
+    {
+        if k > n
+        {
+            return Some(0)
+        }
+        let k = if k > n - k {n - k} else {k};
+        let mut b = 1usize;
+        let mut i = 0;
+        while i < k
+        {
+            // Exact, since b*(n - i) is a product of i + 1 consecutive integers
+            b = match b.checked_mul(n - i)
+            {
+                Some(b) => b/(i + 1),
+                None => return None
+            };
+            i += 1;
+        }
+        Some(b)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::Combinations;
+
+    #[test]
+    fn it_works()
+    {
+        let mut c = Combinations::<6, 3>::new();
+        assert_eq!(c.size_hint(), (20, Some(20)));
+        c.next();
+        assert_eq!(c.size_hint(), (19, Some(19)));
+        assert_eq!(c.count(), 19);
+
+        assert_eq!(Combinations::<3, 0>::new().collect::<Vec<_>>(), [[]]);
+        assert_eq!(Combinations::<2, 3>::new().count(), 0);
+        assert_eq!(Combinations::<4, 4>::new().collect::<Vec<_>>(), [[0, 1, 2, 3]]);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,91 @@
+use core::cmp::Ordering;
+
+/// A comparison function, which may be called in const context.
+/// 
+/// Implemented for any `FnMut(&T, &T) -> Ordering`, so a closure can be used anywhere a comparator is expected.
+/// Closures can't be called in const context yet though, so there a type with a const implementation, like [`Ascending`], has to be used.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use core::cmp::Ordering;
+/// 
+/// use array__ops::Comparator;
+/// 
+/// let mut descending = |a: &i32, b: &i32| b.cmp(a);
+/// 
+/// assert_eq!(descending.compare(&1, &2), Ordering::Greater);
+/// ```
+#[const_trait]
+pub trait Comparator<T>
+{
+    /// Compares two values.
+    fn compare(&mut self, a: &T, b: &T) -> Ordering;
+}
+
+impl<T, F> Comparator<T> for F
+where
+    F: FnMut(&T, &T) -> Ordering
+{
+    fn compare(&mut self, a: &T, b: &T) -> Ordering
+    {
+        self(a, b)
+    }
+}
+
+/// Compares values by their [`Ord`] implementation.
+/// 
+/// This is a const comparator for the primitive integers, `bool` and `char`.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// #![feature(const_trait_impl)]
+/// 
+/// use core::cmp::Ordering;
+/// 
+/// use array__ops::{Ascending, Comparator};
+/// 
+/// const C: Ordering = Ascending.compare(&1, &2);
+/// 
+/// assert_eq!(C, Ordering::Less);
+/// assert_eq!(Ascending.compare(&"b", &"a"), Ordering::Greater);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Hash)]
+pub struct Ascending;
+
+impl<T> Comparator<T> for Ascending
+where
+    T: Ord
+{
+    default fn compare(&mut self, a: &T, b: &T) -> Ordering
+    {
+        a.cmp(b)
+    }
+}
+
+macro_rules! impl_const_ascending {
+    ($($t:ty),*) => {
+        $(
+            impl const Comparator<$t> for Ascending
+            {
+                fn compare(&mut self, a: &$t, b: &$t) -> Ordering
+                {
+                    if *a < *b
+                    {
+                        Ordering::Less
+                    }
+                    else if *a > *b
+                    {
+                        Ordering::Greater
+                    }
+                    else
+                    {
+                        Ordering::Equal
+                    }
+                }
+            }
+        )*
+    };
+}
+impl_const_ascending!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, bool, char);
\ No newline at end of file
@@ -50,7 +50,11 @@ extern crate alloc;
 
 moddef::moddef!(
     flat(pub) mod {
-        from_fn for warn(non_snake_case)
+        combinations for warn(non_snake_case),
+        comparator for warn(non_snake_case),
+        from_fn for warn(non_snake_case),
+        permutation for warn(non_snake_case),
+        rng for warn(non_snake_case)
     },
     pub mod {
         future for warn(non_snake_case),
@@ -62,6 +66,7 @@ moddef::moddef!(
 
 pub use array_trait::*;
 pub use slice_ops::padded;
+pub use num_complex::Complex;
 
 pub const fn min_len(a: usize, b: usize) -> usize
 {
@@ -172,6 +177,12 @@ pub mod asm
         a.bit_rev_permutation()
     }
 
+    #[inline(never)]
+    pub fn walsh_hadamard(a: &mut [i32; 1 << I])
+    {
+        a.walsh_hadamard()
+    }
+
     #[inline(never)]
     pub fn mul_cross(a: &[i32; 3], b: &[i32; 3]) -> [i32; 3]
     {
@@ -193,6 +204,11 @@ pub mod asm
         a.mul_kronecker(b)
     }
     #[inline(never)]
+    pub fn mul_matrix(a: &[[i32; MN.1]; MN.0], b: &[[i32; HW.1]; MN.1]) -> [[i32; HW.1]; MN.0]
+    {
+        a.mul_matrix(b)
+    }
+    #[inline(never)]
     pub fn proj(a: [f32; N], b: [f32; N]) -> [f32; N]
     {
         a.proj(b)
@@ -0,0 +1,298 @@
+use core::cmp::Ordering;
+
+use array_trait::Array;
+use slice_ops::AsSlice;
+
+/// Arbitrary-precision unsigned integer arithmetic, where the array holds the limbs of a number in little-endian order (least significant limb first).
+/// 
+/// Implemented for `[u32; N]` and `[u64; N]`.
+#[const_trait]
+pub trait ArrayBigInt<T, const N: usize>: Array + AsSlice<Item = T>
+{
+    /// Adds another number in-place, propagating the carry across limbs. Returns the carry out of the most significant limb.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let mut a = [u64::MAX, 0];
+    /// 
+    /// assert!(!a.add_carry(&[1, 0]));
+    /// assert_eq!(a, [0, 1]);
+    /// 
+    /// let mut a = [u64::MAX, u64::MAX];
+    /// 
+    /// assert!(a.add_carry(&[1, 0]));
+    /// assert_eq!(a, [0, 0]);
+    /// ```
+    fn add_carry(&mut self, rhs: &[T; N]) -> bool;
+    /// Subtracts another number in-place, propagating the borrow across limbs. Returns the borrow out of the most significant limb.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let mut a = [0, 1];
+    /// 
+    /// assert!(!a.sub_borrow(&[1, 0]));
+    /// assert_eq!(a, [u64::MAX, 0]);
+    /// 
+    /// let mut a = [0, 0];
+    /// 
+    /// assert!(a.sub_borrow(&[1, 0]));
+    /// assert_eq!(a, [u64::MAX, u64::MAX]);
+    /// ```
+    fn sub_borrow(&mut self, rhs: &[T; N]) -> bool;
+    /// Multiplies two numbers, producing the full-width product.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [u32::MAX, u32::MAX];
+    /// let b = [u32::MAX];
+    /// 
+    /// // (2⁶⁴ - 1)(2³² - 1) = 2⁹⁶ - 2⁶⁴ - 2³² + 1
+    /// assert_eq!(a.mul_wide(&b), [1, u32::MAX, u32::MAX - 1]);
+    /// ```
+    fn mul_wide<const M: usize>(&self, rhs: &[T; M]) -> [T; N + M]
+    where
+        [(); N + M]:;
+    /// Shifts the number left by a number of bits, across limbs. Bits shifted past the most significant limb are lost.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let mut a = [0x8000_0001u32, 0, 0];
+    /// a.shl_bits(33);
+    /// 
+    /// assert_eq!(a, [0, 2, 1]);
+    /// ```
+    fn shl_bits(&mut self, shift: usize);
+    /// Shifts the number right by a number of bits, across limbs. Bits shifted past the least significant limb are lost.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let mut a = [0, 2, 1u32];
+    /// a.shr_bits(33);
+    /// 
+    /// assert_eq!(a, [0x8000_0001, 0, 0]);
+    /// ```
+    fn shr_bits(&mut self, shift: usize);
+    /// Compares two numbers by value, starting from the most significant limb.
+    /// 
+    /// This is not the same as [Ord::cmp], which compares arrays lexicographically starting from the first (least significant) limb.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use core::cmp::Ordering;
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [2u64, 0];
+    /// let b = [1u64, 1];
+    /// 
+    /// assert_eq!(a.cmp_limbs(&b), Ordering::Less);
+    /// assert_eq!(a.cmp(&b), Ordering::Greater);
+    /// ```
+    fn cmp_limbs(&self, rhs: &[T; N]) -> Ordering;
+    /// Divides the number by a single limb, returning the quotient and remainder, or `None` if the divisor is zero.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// // 2⁶⁴ + 7 = 10*1844674407370955162 + 3
+    /// let a = [7u64, 1];
+    /// 
+    /// assert_eq!(a.div_rem_small(10), Some(([1844674407370955162, 0], 3)));
+    /// assert_eq!(a.div_rem_small(0), None);
+    /// ```
+    fn div_rem_small(self, rhs: T) -> Option<([T; N], T)>;
+}
+
+macro_rules! impl_big_int {
+    ($($t:ty: $w:ty),*) => {
+        $(
+            impl<const N: usize> const ArrayBigInt<$t, N> for [$t; N]
+            {
+                fn add_carry(&mut self, rhs: &[$t; N]) -> bool
+                {
+                    let mut carry = false;
+                    let mut i = 0;
+                    while i < N
+                    {
+                        let (x, c1) = self[i].overflowing_add(rhs[i]);
+                        let (x, c2) = x.overflowing_add(carry as $t);
+                        self[i] = x;
+                        carry = c1 | c2;
+                        i += 1;
+                    }
+                    carry
+                }
+                fn sub_borrow(&mut self, rhs: &[$t; N]) -> bool
+                {
+                    let mut borrow = false;
+                    let mut i = 0;
+                    while i < N
+                    {
+                        let (x, b1) = self[i].overflowing_sub(rhs[i]);
+                        let (x, b2) = x.overflowing_sub(borrow as $t);
+                        self[i] = x;
+                        borrow = b1 | b2;
+                        i += 1;
+                    }
+                    borrow
+                }
+                fn mul_wide<const M: usize>(&self, rhs: &[$t; M]) -> [$t; N + M]
+                where
+                    [(); N + M]:
+                {
+                    // Schoolbook multiplication, one row at a time
+                    let mut product = [0; N + M];
+                    let mut i = 0;
+                    while i < N
+                    {
+                        let mut carry: $t = 0;
+                        let mut j = 0;
+                        while j < M
+                        {
+                            // Can't overflow, since (2ᵇ - 1)² + 2(2ᵇ - 1) = 2²ᵇ - 1
+                            let x = self[i] as $w*rhs[j] as $w + product[i + j] as $w + carry as $w;
+                            product[i + j] = x as $t;
+                            carry = (x >> <$t>::BITS) as $t;
+                            j += 1;
+                        }
+                        product[i + M] = carry;
+                        i += 1;
+                    }
+                    product
+                }
+                fn shl_bits(&mut self, shift: usize)
+                {
+                    let limbs = shift/<$t>::BITS as usize;
+                    let bits = (shift % <$t>::BITS as usize) as u32;
+                    let mut i = N;
+                    while i > 0
+                    {
+                        i -= 1;
+                        self[i] = if i < limbs
+                        {
+                            0
+                        }
+                        else
+                        {
+                            let j = i - limbs;
+                            let lo = if bits != 0 && j > 0 {self[j - 1] >> (<$t>::BITS - bits)} else {0};
+                            (self[j] << bits) | lo
+                        };
+                    }
+                }
+                fn shr_bits(&mut self, shift: usize)
+                {
+                    let limbs = shift/<$t>::BITS as usize;
+                    let bits = (shift % <$t>::BITS as usize) as u32;
+                    let mut i = 0;
+                    while i < N
+                    {
+                        self[i] = if i + limbs >= N
+                        {
+                            0
+                        }
+                        else
+                        {
+                            let j = i + limbs;
+                            let hi = if bits != 0 && j + 1 < N {self[j + 1] << (<$t>::BITS - bits)} else {0};
+                            (self[j] >> bits) | hi
+                        };
+                        i += 1;
+                    }
+                }
+                fn cmp_limbs(&self, rhs: &[$t; N]) -> Ordering
+                {
+                    let mut i = N;
+                    while i > 0
+                    {
+                        i -= 1;
+                        if self[i] != rhs[i]
+                        {
+                            return if self[i] < rhs[i] {Ordering::Less} else {Ordering::Greater}
+                        }
+                    }
+                    Ordering::Equal
+                }
+                fn div_rem_small(mut self, rhs: $t) -> Option<([$t; N], $t)>
+                {
+                    if rhs == 0
+                    {
+                        return None
+                    }
+                    let mut remainder: $t = 0;
+                    let mut i = N;
+                    while i > 0
+                    {
+                        i -= 1;
+                        // The remainder is less than the divisor, so the quotient fits in one limb
+                        let x = ((remainder as $w) << <$t>::BITS) | self[i] as $w;
+                        self[i] = (x/rhs as $w) as $t;
+                        remainder = (x % rhs as $w) as $t;
+                    }
+                    Some((self, remainder))
+                }
+            }
+        )*
+    };
+}
+
+impl_big_int!(u32: u64, u64: u128);
+
+#[cfg(test)]
+mod test
+{
+    use core::cmp::Ordering;
+
+    use crate::ops::*;
+
+    #[test]
+    fn it_works()
+    {
+        let a = [0x89ab_cdefu32, 0x0123_4567, 0xdead_beef];
+        let b = [0xffff_fff1u32, 0x8000_0000];
+
+        let to_u128 = |x: &[u32]| x.iter().rev().fold(0u128, |acc, &x| (acc << 32) | x as u128);
+
+        assert_eq!(to_u128(&a.mul_wide(&[b[0]])), to_u128(&a)*b[0] as u128);
+        assert_eq!(to_u128(&[a[0], a[1]].mul_wide(&b)), to_u128(&a[..2])*to_u128(&b));
+
+        let mut c = a;
+        c.shl_bits(45);
+        c.shr_bits(45);
+        let mut d = a;
+        d.shl_bits(96 - 45);
+        d.shr_bits(96 - 45);
+        assert_eq!(to_u128(&c), to_u128(&a) & ((1 << (96 - 45)) - 1));
+        assert_eq!(to_u128(&d), to_u128(&a) & ((1 << 45) - 1));
+
+        let (q, r) = a.div_rem_small(12345).unwrap();
+        assert_eq!(to_u128(&q), to_u128(&a)/12345);
+        assert_eq!(r as u128, to_u128(&a) % 12345);
+
+        let mut e = a;
+        assert!(e.add_carry(&[u32::MAX; 3]));
+        assert!(e.sub_borrow(&[u32::MAX; 3]));
+        assert_eq!(e, a);
+        assert_eq!(e.cmp_limbs(&[0, 0, 0xdead_bef0]), Ordering::Less);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,78 @@
+/// Cartesian product of several arrays. This is an extension of [ArrayZipOuter::zip_outer](crate::ops::ArrayZipOuter::zip_outer) to any number of operands.
+/// 
+/// Implemented for tuples of up to 8 array references. The result is a nested array, where the first operand is the outermost dimension.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use array__ops::ops::*;
+/// 
+/// let a = [1, 2];
+/// let b = ['x', 'y'];
+/// let c = [true, false];
+/// 
+/// let p = (&a, &b, &c).cartesian_product();
+/// 
+/// assert_eq!(p, [
+///     [
+///         [(1, 'x', true), (1, 'x', false)],
+///         [(1, 'y', true), (1, 'y', false)]
+///     ],
+///     [
+///         [(2, 'x', true), (2, 'x', false)],
+///         [(2, 'y', true), (2, 'y', false)]
+///     ]
+/// ]);
+/// 
+/// assert_eq!((&a, &b).cartesian_product(), a.zip_outer(&b));
+/// ```
+pub trait CartesianProduct
+{
+    type Output;
+
+    fn cartesian_product(self) -> Self::Output;
+}
+
+macro_rules! nest {
+    ($t:ty;) => {
+        $t
+    };
+    ($t:ty; $a:ident $($b:ident)*) => {
+        [nest!{$t; $($b)*}; $a]
+    };
+}
+
+macro_rules! fill {
+    ($value:expr;) => {
+        $value
+    };
+    ($value:expr; $i:ident $($j:ident)*) => {
+        crate::from_fn(|$i| fill!{$value; $($j)*})
+    };
+}
+
+macro_rules! impl_cartesian_product {
+    ($(($t:ident, $n:ident, $a:ident, $i:ident)),+) => {
+        impl<$($t,)+ $(const $n: usize),+> CartesianProduct for ($(&[$t; $n],)+)
+        where
+            $($t: Copy),+
+        {
+            type Output = nest!{($($t,)+); $($n)+};
+
+            fn cartesian_product(self) -> Self::Output
+            {
+                let ($($a,)+) = self;
+                fill!{($($a[$i],)+); $($i)+}
+            }
+        }
+    };
+}
+
+impl_cartesian_product!((T0, N0, a0, i0));
+impl_cartesian_product!((T0, N0, a0, i0), (T1, N1, a1, i1));
+impl_cartesian_product!((T0, N0, a0, i0), (T1, N1, a1, i1), (T2, N2, a2, i2));
+impl_cartesian_product!((T0, N0, a0, i0), (T1, N1, a1, i1), (T2, N2, a2, i2), (T3, N3, a3, i3));
+impl_cartesian_product!((T0, N0, a0, i0), (T1, N1, a1, i1), (T2, N2, a2, i2), (T3, N3, a3, i3), (T4, N4, a4, i4));
+impl_cartesian_product!((T0, N0, a0, i0), (T1, N1, a1, i1), (T2, N2, a2, i2), (T3, N3, a3, i3), (T4, N4, a4, i4), (T5, N5, a5, i5));
+impl_cartesian_product!((T0, N0, a0, i0), (T1, N1, a1, i1), (T2, N2, a2, i2), (T3, N3, a3, i3), (T4, N4, a4, i4), (T5, N5, a5, i5), (T6, N6, a6, i6));
+impl_cartesian_product!((T0, N0, a0, i0), (T1, N1, a1, i1), (T2, N2, a2, i2), (T3, N3, a3, i3), (T4, N4, a4, i4), (T5, N5, a5, i5), (T6, N6, a6, i6), (T7, N7, a7, i7));
\ No newline at end of file
@@ -0,0 +1,218 @@
+use array_trait::Array;
+use num_traits::Float;
+use slice_ops::AsSlice;
+
+use super::{ArrayDivideAndConquer, ArrayMap, ArrayZipWith};
+
+#[const_trait]
+pub trait ArrayCompensatedSum<T, const N: usize>: Array + AsSlice<Item = T>
+{
+    /// Sums the elements using Kahan-Babuška-Neumaier compensated summation.
+    /// 
+    /// The rounding error is independent of the length of the array, unlike when summing naively from left to right.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [0.1f32; 10000];
+    /// 
+    /// let exact: f64 = a.iter().map(|&x| x as f64).sum();
+    /// let naive: f32 = a.iter().sum();
+    /// let kahan = a.sum_kahan();
+    /// 
+    /// let naive_error = (naive as f64 - exact).abs();
+    /// let kahan_error = (kahan as f64 - exact).abs();
+    /// 
+    /// assert!(naive_error > 1e-2);
+    /// assert!(kahan_error < 1e-3);
+    /// ```
+    fn sum_kahan(&self) -> T
+    where
+        T: Float;
+    /// Sums the elements pairwise, in a balanced binary tree.
+    /// 
+    /// The rounding error grows logarithmically with the length of the array, instead of linearly as when summing naively from left to right.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [0.1f32; 10000];
+    /// 
+    /// let exact: f64 = a.iter().map(|&x| x as f64).sum();
+    /// let naive: f32 = a.iter().sum();
+    /// let pairwise = a.sum_pairwise();
+    /// 
+    /// let naive_error = (naive as f64 - exact).abs();
+    /// let pairwise_error = (pairwise as f64 - exact).abs();
+    /// 
+    /// assert!(naive_error > 1e-2);
+    /// assert!(pairwise_error < 1e-3);
+    /// ```
+    fn sum_pairwise(&self) -> T
+    where
+        T: Float;
+    /// Computes the dot product using error-free transformations of each product and compensated summation.
+    /// 
+    /// The result is as accurate as if computed with twice the working precision.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [1e8f32, 1.0, -1e8, 1.0];
+    /// let b = [1.0f32, 1.0, 1.0, 1.0];
+    /// 
+    /// let naive: f32 = a.iter().zip(b.iter()).map(|(a, b)| a*b).sum();
+    /// 
+    /// assert_eq!(naive, 1.0);
+    /// assert_eq!(a.mul_dot_compensated(&b), 2.0);
+    /// ```
+    fn mul_dot_compensated(&self, rhs: &[T; N]) -> T
+    where
+        T: Float;
+
+    async fn sum_kahan_async(&self) -> T
+    where
+        T: Float;
+    async fn sum_pairwise_async(&self) -> T
+    where
+        T: Float;
+    async fn mul_dot_compensated_async(&self, rhs: &[T; N]) -> T
+    where
+        T: Float;
+}
+
+impl<T, const N: usize> ArrayCompensatedSum<T, N> for [T; N]
+{
+    fn sum_kahan(&self) -> T
+    where
+        T: Float
+    {
+        let mut sum = T::zero();
+        let mut c = T::zero();
+        let mut i = 0;
+        while i < N
+        {
+            let e;
+            (sum, e) = r#impl::two_sum(sum, self[i]);
+            c = c + e;
+            i += 1;
+        }
+        sum + c
+    }
+    fn sum_pairwise(&self) -> T
+    where
+        T: Float
+    {
+        self.divide_and_conquer(|x, y| x + y)
+            .unwrap_or_else(T::zero)
+    }
+    fn mul_dot_compensated(&self, rhs: &[T; N]) -> T
+    where
+        T: Float
+    {
+        let mut sum = T::zero();
+        let mut c = T::zero();
+        let mut i = 0;
+        while i < N
+        {
+            let (p, ep) = r#impl::two_product(self[i], rhs[i]);
+            let es;
+            (sum, es) = r#impl::two_sum(sum, p);
+            c = c + (ep + es);
+            i += 1;
+        }
+        sum + c
+    }
+
+    async fn sum_kahan_async(&self) -> T
+    where
+        T: Float
+    {
+        self.map_async(async |x| (x, T::zero())).await
+            .divide_and_conquer_async(async |x, y| r#impl::merge_compensated(x, y)).await
+            .map(|(sum, c)| sum + c)
+            .unwrap_or_else(T::zero)
+    }
+    async fn sum_pairwise_async(&self) -> T
+    where
+        T: Float
+    {
+        self.divide_and_conquer_async(async |x, y| x + y).await
+            .unwrap_or_else(T::zero)
+    }
+    async fn mul_dot_compensated_async(&self, rhs: &[T; N]) -> T
+    where
+        T: Float
+    {
+        self.zip_async_with(*rhs, async |x, y| r#impl::two_product(x, y)).await
+            .divide_and_conquer_async(async |x, y| r#impl::merge_compensated(x, y)).await
+            .map(|(sum, c)| sum + c)
+            .unwrap_or_else(T::zero)
+    }
+}
+
+mod r#impl
+{
+    use num_traits::Float;
+
+    /// Error-free transformation of a sum. Returns the rounded sum and its rounding error.
+    pub(super) fn two_sum<T>(a: T, b: T) -> (T, T)
+    where
+        T: Float
+    {
+        let s = a + b;
+        let bb = s - a;
+        let e = (a - (s - bb)) + (b - bb);
+        (s, e)
+    }
+
+    /// Error-free transformation of a product. Returns the rounded product and its rounding error.
+    pub(super) fn two_product<T>(a: T, b: T) -> (T, T)
+    where
+        T: Float
+    {
+        let p = a*b;
+        let e = a.mul_add(b, -p);
+        (p, e)
+    }
+
+    /// Adds two compensated sums, each given as a rounded sum and its accumulated error.
+    pub(super) fn merge_compensated<T>((s1, c1): (T, T), (s2, c2): (T, T)) -> (T, T)
+    where
+        T: Float
+    {
+        let (s, e) = two_sum(s1, s2);
+        (s, c1 + c2 + e)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::ops::*;
+
+    #[test]
+    fn it_works()
+    {
+        let a: [f32; 1000] = core::array::from_fn(|i| 1.0/(i + 1) as f32);
+        let b: [f32; 1000] = core::array::from_fn(|i| (i as f32).sin());
+
+        let exact: f64 = a.iter().map(|&x| x as f64).sum();
+        let exact_dot: f64 = a.iter().zip(b.iter()).map(|(&a, &b)| a as f64*b as f64).sum();
+
+        tokio_test::block_on(async {
+            assert!((a.sum_kahan() as f64 - exact).abs() < 1e-6);
+            assert!((a.sum_kahan_async().await as f64 - exact).abs() < 1e-6);
+            assert!((a.sum_pairwise() as f64 - exact).abs() < 1e-5);
+            assert!((a.sum_pairwise_async().await as f64 - exact).abs() < 1e-5);
+            assert!((a.mul_dot_compensated(&b) as f64 - exact_dot).abs() < 1e-6);
+            assert!((a.mul_dot_compensated_async(&b).await as f64 - exact_dot).abs() < 1e-6);
+        });
+    }
+}
\ No newline at end of file
@@ -0,0 +1,328 @@
+use core::ops::{AddAssign, Mul};
+
+use array_trait::Array;
+use slice_ops::AsSlice;
+
+use crate::form::ArrayForm;
+
+#[const_trait]
+pub trait ArrayConvolve<T, const N: usize>: Array + AsSlice<Item = T>
+{
+    /// Performs a full linear convolution with a kernel.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [1, 2, 3, 4];
+    /// 
+    /// assert_eq!(a.convolve(&[1, 1, 1]), [1, 3, 6, 9, 7, 4]);
+    /// ```
+    fn convolve<Rhs, const M: usize>(&self, rhs: &Rhs) -> [<T as Mul<Rhs::Elem>>::Output; N + M - 1]
+    where
+        T: Mul<Rhs::Elem, Output: AddAssign + Default> + Copy,
+        Rhs: ArrayForm<M, Elem: Copy>,
+        [(); N + M - 1]:;
+    /// Performs a linear convolution with a kernel, keeping only the centre part with the same length as the input.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [1, 2, 3, 4];
+    /// 
+    /// assert_eq!(a.convolve_same(&[1, 1, 1]), [3, 6, 9, 7]);
+    /// ```
+    fn convolve_same<Rhs, const M: usize>(&self, rhs: &Rhs) -> [<T as Mul<Rhs::Elem>>::Output; N]
+    where
+        T: Mul<Rhs::Elem, Output: AddAssign + Default> + Copy,
+        Rhs: ArrayForm<M, Elem: Copy>;
+    /// Performs a linear convolution with a kernel, keeping only the part where the kernel fully overlaps the input.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [1, 2, 3, 4];
+    /// 
+    /// assert_eq!(a.convolve_valid(&[1, 1, 1]), [6, 9]);
+    /// ```
+    fn convolve_valid<Rhs, const M: usize>(&self, rhs: &Rhs) -> [<T as Mul<Rhs::Elem>>::Output; N - M + 1]
+    where
+        T: Mul<Rhs::Elem, Output: AddAssign + Default> + Copy,
+        Rhs: ArrayForm<M, Elem: Copy>,
+        [(); N - M + 1]:;
+
+    /// Performs a full cross-correlation with a kernel.
+    /// 
+    /// This is equivalent to a convolution with the kernel reversed.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [1, 2, 3, 4];
+    /// 
+    /// assert_eq!(a.correlate(&[1, 0, -1]), [-1, -2, -2, -2, 3, 4]);
+    /// ```
+    fn correlate<Rhs, const M: usize>(&self, rhs: &Rhs) -> [<T as Mul<Rhs::Elem>>::Output; N + M - 1]
+    where
+        T: Mul<Rhs::Elem, Output: AddAssign + Default> + Copy,
+        Rhs: ArrayForm<M, Elem: Copy>,
+        [(); N + M - 1]:;
+    /// Performs a cross-correlation with a kernel, keeping only the centre part with the same length as the input.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [1, 2, 3, 4];
+    /// 
+    /// assert_eq!(a.correlate_same(&[1, 0, -1]), [-2, -2, -2, 3]);
+    /// ```
+    fn correlate_same<Rhs, const M: usize>(&self, rhs: &Rhs) -> [<T as Mul<Rhs::Elem>>::Output; N]
+    where
+        T: Mul<Rhs::Elem, Output: AddAssign + Default> + Copy,
+        Rhs: ArrayForm<M, Elem: Copy>;
+    /// Performs a cross-correlation with a kernel, keeping only the part where the kernel fully overlaps the input.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [1, 2, 3, 4];
+    /// 
+    /// assert_eq!(a.correlate_valid(&[1, 0, -1]), [-2, -2]);
+    /// ```
+    fn correlate_valid<Rhs, const M: usize>(&self, rhs: &Rhs) -> [<T as Mul<Rhs::Elem>>::Output; N - M + 1]
+    where
+        T: Mul<Rhs::Elem, Output: AddAssign + Default> + Copy,
+        Rhs: ArrayForm<M, Elem: Copy>,
+        [(); N - M + 1]:;
+
+    async fn convolve_async<Rhs, const M: usize>(&self, rhs: &Rhs) -> [<T as Mul<Rhs::Elem>>::Output; N + M - 1]
+    where
+        T: Mul<Rhs::Elem, Output: AddAssign + Default> + Copy,
+        Rhs: ArrayForm<M, Elem: Copy>,
+        [(); N + M - 1]:;
+    async fn convolve_same_async<Rhs, const M: usize>(&self, rhs: &Rhs) -> [<T as Mul<Rhs::Elem>>::Output; N]
+    where
+        T: Mul<Rhs::Elem, Output: AddAssign + Default> + Copy,
+        Rhs: ArrayForm<M, Elem: Copy>;
+    async fn convolve_valid_async<Rhs, const M: usize>(&self, rhs: &Rhs) -> [<T as Mul<Rhs::Elem>>::Output; N - M + 1]
+    where
+        T: Mul<Rhs::Elem, Output: AddAssign + Default> + Copy,
+        Rhs: ArrayForm<M, Elem: Copy>,
+        [(); N - M + 1]:;
+
+    async fn correlate_async<Rhs, const M: usize>(&self, rhs: &Rhs) -> [<T as Mul<Rhs::Elem>>::Output; N + M - 1]
+    where
+        T: Mul<Rhs::Elem, Output: AddAssign + Default> + Copy,
+        Rhs: ArrayForm<M, Elem: Copy>,
+        [(); N + M - 1]:;
+    async fn correlate_same_async<Rhs, const M: usize>(&self, rhs: &Rhs) -> [<T as Mul<Rhs::Elem>>::Output; N]
+    where
+        T: Mul<Rhs::Elem, Output: AddAssign + Default> + Copy,
+        Rhs: ArrayForm<M, Elem: Copy>;
+    async fn correlate_valid_async<Rhs, const M: usize>(&self, rhs: &Rhs) -> [<T as Mul<Rhs::Elem>>::Output; N - M + 1]
+    where
+        T: Mul<Rhs::Elem, Output: AddAssign + Default> + Copy,
+        Rhs: ArrayForm<M, Elem: Copy>,
+        [(); N - M + 1]:;
+}
+
+impl<T, const N: usize> ArrayConvolve<T, N> for [T; N]
+{
+    fn convolve<Rhs, const M: usize>(&self, rhs: &Rhs) -> [<T as Mul<Rhs::Elem>>::Output; N + M - 1]
+    where
+        T: Mul<Rhs::Elem, Output: AddAssign + Default> + Copy,
+        Rhs: ArrayForm<M, Elem: Copy>,
+        [(); N + M - 1]:
+    {
+        crate::from_fn(|i| r#impl::convolve_at(self, rhs, i))
+    }
+    fn convolve_same<Rhs, const M: usize>(&self, rhs: &Rhs) -> [<T as Mul<Rhs::Elem>>::Output; N]
+    where
+        T: Mul<Rhs::Elem, Output: AddAssign + Default> + Copy,
+        Rhs: ArrayForm<M, Elem: Copy>
+    {
+        crate::from_fn(|i| r#impl::convolve_at(self, rhs, i + M.saturating_sub(1)/2))
+    }
+    fn convolve_valid<Rhs, const M: usize>(&self, rhs: &Rhs) -> [<T as Mul<Rhs::Elem>>::Output; N - M + 1]
+    where
+        T: Mul<Rhs::Elem, Output: AddAssign + Default> + Copy,
+        Rhs: ArrayForm<M, Elem: Copy>,
+        [(); N - M + 1]:
+    {
+        crate::from_fn(|i| r#impl::convolve_at(self, rhs, (i + M).saturating_sub(1)))
+    }
+
+    fn correlate<Rhs, const M: usize>(&self, rhs: &Rhs) -> [<T as Mul<Rhs::Elem>>::Output; N + M - 1]
+    where
+        T: Mul<Rhs::Elem, Output: AddAssign + Default> + Copy,
+        Rhs: ArrayForm<M, Elem: Copy>,
+        [(); N + M - 1]:
+    {
+        crate::from_fn(|i| r#impl::correlate_at(self, rhs, i))
+    }
+    fn correlate_same<Rhs, const M: usize>(&self, rhs: &Rhs) -> [<T as Mul<Rhs::Elem>>::Output; N]
+    where
+        T: Mul<Rhs::Elem, Output: AddAssign + Default> + Copy,
+        Rhs: ArrayForm<M, Elem: Copy>
+    {
+        crate::from_fn(|i| r#impl::correlate_at(self, rhs, i + M.saturating_sub(1)/2))
+    }
+    fn correlate_valid<Rhs, const M: usize>(&self, rhs: &Rhs) -> [<T as Mul<Rhs::Elem>>::Output; N - M + 1]
+    where
+        T: Mul<Rhs::Elem, Output: AddAssign + Default> + Copy,
+        Rhs: ArrayForm<M, Elem: Copy>,
+        [(); N - M + 1]:
+    {
+        crate::from_fn(|i| r#impl::correlate_at(self, rhs, (i + M).saturating_sub(1)))
+    }
+
+    async fn convolve_async<Rhs, const M: usize>(&self, rhs: &Rhs) -> [<T as Mul<Rhs::Elem>>::Output; N + M - 1]
+    where
+        T: Mul<Rhs::Elem, Output: AddAssign + Default> + Copy,
+        Rhs: ArrayForm<M, Elem: Copy>,
+        [(); N + M - 1]:
+    {
+        crate::from_fn_async(async |i| r#impl::convolve_at(self, rhs, i)).await
+    }
+    async fn convolve_same_async<Rhs, const M: usize>(&self, rhs: &Rhs) -> [<T as Mul<Rhs::Elem>>::Output; N]
+    where
+        T: Mul<Rhs::Elem, Output: AddAssign + Default> + Copy,
+        Rhs: ArrayForm<M, Elem: Copy>
+    {
+        crate::from_fn_async(async |i| r#impl::convolve_at(self, rhs, i + M.saturating_sub(1)/2)).await
+    }
+    async fn convolve_valid_async<Rhs, const M: usize>(&self, rhs: &Rhs) -> [<T as Mul<Rhs::Elem>>::Output; N - M + 1]
+    where
+        T: Mul<Rhs::Elem, Output: AddAssign + Default> + Copy,
+        Rhs: ArrayForm<M, Elem: Copy>,
+        [(); N - M + 1]:
+    {
+        crate::from_fn_async(async |i| r#impl::convolve_at(self, rhs, (i + M).saturating_sub(1))).await
+    }
+
+    async fn correlate_async<Rhs, const M: usize>(&self, rhs: &Rhs) -> [<T as Mul<Rhs::Elem>>::Output; N + M - 1]
+    where
+        T: Mul<Rhs::Elem, Output: AddAssign + Default> + Copy,
+        Rhs: ArrayForm<M, Elem: Copy>,
+        [(); N + M - 1]:
+    {
+        crate::from_fn_async(async |i| r#impl::correlate_at(self, rhs, i)).await
+    }
+    async fn correlate_same_async<Rhs, const M: usize>(&self, rhs: &Rhs) -> [<T as Mul<Rhs::Elem>>::Output; N]
+    where
+        T: Mul<Rhs::Elem, Output: AddAssign + Default> + Copy,
+        Rhs: ArrayForm<M, Elem: Copy>
+    {
+        crate::from_fn_async(async |i| r#impl::correlate_at(self, rhs, i + M.saturating_sub(1)/2)).await
+    }
+    async fn correlate_valid_async<Rhs, const M: usize>(&self, rhs: &Rhs) -> [<T as Mul<Rhs::Elem>>::Output; N - M + 1]
+    where
+        T: Mul<Rhs::Elem, Output: AddAssign + Default> + Copy,
+        Rhs: ArrayForm<M, Elem: Copy>,
+        [(); N - M + 1]:
+    {
+        crate::from_fn_async(async |i| r#impl::correlate_at(self, rhs, (i + M).saturating_sub(1))).await
+    }
+}
+
+mod r#impl
+{
+    use core::ops::{AddAssign, Mul};
+
+    use crate::form::ArrayForm;
+
+    /// Sums the `k`'th anti-diagonal of the outer product.
+    pub(super) fn convolve_at<T, Rhs, const N: usize, const M: usize>(lhs: &[T; N], rhs: &Rhs, k: usize) -> <T as Mul<Rhs::Elem>>::Output
+    where
+        T: Mul<Rhs::Elem, Output: AddAssign + Default> + Copy,
+        Rhs: ArrayForm<M, Elem: Copy>
+    {
+        let mut y = Default::default();
+        let mut j = (k + 1).saturating_sub(N);
+        while j < M && j <= k
+        {
+            y += lhs[k - j]*rhs.copy_elem(j);
+            j += 1;
+        }
+        y
+    }
+
+    /// Sums the `k`'th diagonal of the outer product, counting from the bottom-left corner.
+    pub(super) fn correlate_at<T, Rhs, const N: usize, const M: usize>(lhs: &[T; N], rhs: &Rhs, k: usize) -> <T as Mul<Rhs::Elem>>::Output
+    where
+        T: Mul<Rhs::Elem, Output: AddAssign + Default> + Copy,
+        Rhs: ArrayForm<M, Elem: Copy>
+    {
+        let mut y = Default::default();
+        let mut j = M.saturating_sub(k + 1);
+        while j < M && k + j + 1 < N + M
+        {
+            y += lhs[k + j + 1 - M]*rhs.copy_elem(j);
+            j += 1;
+        }
+        y
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::ops::*;
+
+    #[test]
+    fn it_works()
+    {
+        let a = [1, 2, 3, 4, 5];
+        let h = [1, -2];
+
+        let mut h_rev = h;
+        h_rev.reverse();
+
+        assert_eq!(a.convolve(&h), [1, 0, -1, -2, -3, -10]);
+        assert_eq!(a.correlate(&h), a.convolve(&h_rev));
+        assert_eq!(a.correlate_same(&h), a.convolve_same(&h_rev));
+        assert_eq!(a.correlate_valid(&h), a.convolve_valid(&h_rev));
+
+        tokio_test::block_on(async {
+            assert_eq!(a.convolve_async(&h).await, a.convolve(&h));
+            assert_eq!(a.convolve_same_async(&h).await, a.convolve_same(&h));
+            assert_eq!(a.convolve_valid_async(&h).await, a.convolve_valid(&h));
+            assert_eq!(a.correlate_async(&h).await, a.correlate(&h));
+            assert_eq!(a.correlate_same_async(&h).await, a.correlate_same(&h));
+            assert_eq!(a.correlate_valid_async(&h).await, a.correlate_valid(&h));
+        });
+    }
+
+    #[test]
+    fn empty_kernel()
+    {
+        let a = [1, 2, 3];
+        let h: [i32; 0] = [];
+
+        assert_eq!(a.convolve(&h), [0, 0]);
+        assert_eq!(a.convolve_same(&h), [0, 0, 0]);
+        assert_eq!(a.convolve_valid(&h), [0, 0, 0, 0]);
+        assert_eq!(a.correlate(&h), [0, 0]);
+        assert_eq!(a.correlate_same(&h), [0, 0, 0]);
+        assert_eq!(a.correlate_valid(&h), [0, 0, 0, 0]);
+    }
+}
\ No newline at end of file
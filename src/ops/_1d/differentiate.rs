@@ -1,8 +1,11 @@
-use core::ops::SubAssign;
+use core::ops::{AddAssign, Mul, Sub, SubAssign};
 
 use array_trait::Array;
+use num_traits::Num;
 use slice_ops::AsSlice;
 
+use super::{ArrayConvolve, ArrayWindows};
+
 #[const_trait]
 pub trait ArrayDifferentiate<T, const N: usize>: Array + AsSlice<Item = T>
 {
@@ -22,6 +25,83 @@ pub trait ArrayDifferentiate<T, const N: usize>: Array + AsSlice<Item = T>
     fn differentiate(&mut self)
     where
         T: SubAssign<T> + Copy;
+
+    /// Differentiates array using forward differences (discrete calculus)
+    /// 
+    /// The last element is carried over, as there is nothing to subtract from it.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let mut a = [1, 2, 4];
+    /// 
+    /// a.differentiate_forward();
+    /// 
+    /// assert_eq!(a, [2 - 1, 4 - 2, 4]);
+    /// ```
+    fn differentiate_forward(&mut self)
+    where
+        T: Sub<T, Output = T> + Copy;
+
+    /// Differentiates array `K` times, without carrying over any elements (discrete calculus)
+    /// 
+    /// The inverse is [ArrayIntegrate::integrate_n](crate::ops::ArrayIntegrate::integrate_n).
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [1, 4, 9, 16, 25];
+    /// 
+    /// assert_eq!(a.differentiate_n::<1>(), [3, 5, 7, 9]);
+    /// assert_eq!(a.differentiate_n::<2>(), [2, 2, 2]);
+    /// assert_eq!(a.differentiate_n::<3>(), [0, 0]);
+    /// ```
+    fn differentiate_n<const K: usize>(&self) -> [T; N - K]
+    where
+        T: Sub<T, Output = T> + Copy;
+
+    /// Differentiates array using central differences (discrete calculus)
+    /// 
+    /// Each element is half the difference of its neighbours, so the ends are dropped.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [1.0, 4.0, 9.0, 16.0, 25.0];
+    /// 
+    /// assert_eq!(a.differentiate_central(), [4.0, 6.0, 8.0]);
+    /// ```
+    fn differentiate_central(&self) -> [T; N - 2]
+    where
+        T: Num + Copy;
+
+    /// Applies a finite difference stencil, where each element is a weighted sum of the `S` elements starting at its position.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [1, 4, 9, 16, 25];
+    /// 
+    /// // Second order difference
+    /// assert_eq!(a.finite_difference([1, -2, 1]), [2, 2, 2]);
+    /// ```
+    fn finite_difference<const S: usize>(&self, stencil: [T; S]) -> [T; N - S + 1]
+    where
+        T: Mul<T, Output = T> + AddAssign + Default + Copy;
 }
 
 impl<T, const N: usize> ArrayDifferentiate<T, N> for [T; N]
@@ -30,15 +110,47 @@ impl<T, const N: usize> ArrayDifferentiate<T, N> for [T; N]
     where
         T: SubAssign<T> + Copy
     {
-        // TODO: visit_windowed_mut
-        if N > 0
+        self.rvisit_windows_mut(|[a, b]: &mut [T; 2]| *b -= *a)
+    }
+
+    fn differentiate_forward(&mut self)
+    where
+        T: Sub<T, Output = T> + Copy
+    {
+        self.visit_windows_mut(|[a, b]: &mut [T; 2]| *a = *b - *a)
+    }
+
+    fn differentiate_n<const K: usize>(&self) -> [T; N - K]
+    where
+        T: Sub<T, Output = T> + Copy
+    {
+        let mut y = *self;
+        let mut k = 0;
+        while k < K
         {
-            let mut i = N - 1;
-            while i > 0
+            let mut i = 0;
+            while i + k + 1 < N
             {
-                self[i] -= self[i - 1];
-                i -= 1;
+                y[i] = y[i + 1] - y[i];
+                i += 1;
             }
+            k += 1;
         }
+        crate::from_fn(|i| y[i])
+    }
+
+    fn differentiate_central(&self) -> [T; N - 2]
+    where
+        T: Num + Copy
+    {
+        let two = T::one() + T::one();
+        crate::from_fn(|i| (self[i + 2] - self[i])/two)
+    }
+
+    fn finite_difference<const S: usize>(&self, stencil: [T; S]) -> [T; N - S + 1]
+    where
+        T: Mul<T, Output = T> + AddAssign + Default + Copy
+    {
+        self.correlate_valid(&stencil)
     }
 }
\ No newline at end of file
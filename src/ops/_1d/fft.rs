@@ -0,0 +1,290 @@
+use array_trait::Array;
+use num_complex::Complex;
+use num_traits::{Float, FloatConst};
+use slice_ops::AsSlice;
+
+#[const_trait]
+pub trait ArrayFft<T, const N: usize>: Array + AsSlice<Item = T>
+{
+    /// Performs the discrete fourier transform using a radix-2 Cooley-Tukey FFT. Length must be a power of 2.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::{ops::*, Complex};
+    /// 
+    /// let x = [1.0, 1.0, 0.0, 0.0];
+    /// 
+    /// let y: [Complex<f64>; 4] = x.fft();
+    /// 
+    /// let expected = [
+    ///     Complex::new(2.0, 0.0),
+    ///     Complex::new(1.0, -1.0),
+    ///     Complex::new(0.0, 0.0),
+    ///     Complex::new(1.0, 1.0)
+    /// ];
+    /// for (y, e) in y.into_iter().zip(expected)
+    /// {
+    ///     assert!((y - e).norm() < 1e-12);
+    /// }
+    /// ```
+    fn fft<F>(&self) -> [Complex<F>; N]
+    where
+        T: Into<Complex<F>> + Copy,
+        F: Float + FloatConst,
+        [(); slice_ops::is_power_of(N, 2) as usize - 1]:;
+
+    /// Performs the inverse discrete fourier transform using a radix-2 Cooley-Tukey FFT. Length must be a power of 2.
+    /// 
+    /// The result is normalized, so that this is the exact inverse of [ArrayFft::fft].
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::{ops::*, Complex};
+    /// 
+    /// let x = [1.0, 2.0, 3.0, 4.0];
+    /// 
+    /// let y: [Complex<f64>; 4] = x.fft();
+    /// let z = y.ifft();
+    /// 
+    /// for (z, x) in z.into_iter().zip(x)
+    /// {
+    ///     assert!((z - x).norm() < 1e-12);
+    /// }
+    /// ```
+    fn ifft<F>(&self) -> [Complex<F>; N]
+    where
+        T: Into<Complex<F>> + Copy,
+        F: Float + FloatConst,
+        [(); slice_ops::is_power_of(N, 2) as usize - 1]:;
+
+    /// Performs the discrete fourier transform using a radix-`R` Cooley-Tukey FFT. Length must be a power of the radix.
+    /// 
+    /// With radix 2, this is equivalent to [ArrayFft::fft].
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::{ops::*, Complex};
+    /// 
+    /// let x = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+    /// 
+    /// let y: [Complex<f64>; 9] = x.fft_radix::<3, _>();
+    /// 
+    /// assert!((y[0] - Complex::new(45.0, 0.0)).norm() < 1e-12);
+    /// 
+    /// let z = y.ifft_radix::<3, _>();
+    /// 
+    /// for (z, x) in z.into_iter().zip(x)
+    /// {
+    ///     assert!((z - x).norm() < 1e-12);
+    /// }
+    /// ```
+    fn fft_radix<const R: usize, F>(&self) -> [Complex<F>; N]
+    where
+        T: Into<Complex<F>> + Copy,
+        F: Float + FloatConst,
+        [(); slice_ops::is_power_of(N, R) as usize - 1]:;
+
+    /// Performs the inverse discrete fourier transform using a radix-`R` Cooley-Tukey FFT. Length must be a power of the radix.
+    /// 
+    /// The result is normalized, so that this is the exact inverse of [ArrayFft::fft_radix].
+    fn ifft_radix<const R: usize, F>(&self) -> [Complex<F>; N]
+    where
+        T: Into<Complex<F>> + Copy,
+        F: Float + FloatConst,
+        [(); slice_ops::is_power_of(N, R) as usize - 1]:;
+
+    /// Performs the discrete fourier transform of a real signal. Length must be a power of 2.
+    /// 
+    /// Since the spectrum of a real signal is conjugate-symmetric, only the first `N/2 + 1` bins are returned.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::{ops::*, Complex};
+    /// 
+    /// let x = [1.0, 1.0, 0.0, 0.0];
+    /// 
+    /// let y = x.rfft();
+    /// 
+    /// let expected = [
+    ///     Complex::new(2.0, 0.0),
+    ///     Complex::new(1.0, -1.0),
+    ///     Complex::new(0.0, 0.0)
+    /// ];
+    /// for (y, e) in y.into_iter().zip(expected)
+    /// {
+    ///     assert!((y - e).norm() < 1e-12);
+    /// }
+    /// ```
+    fn rfft(&self) -> [Complex<T>; N/2 + 1]
+    where
+        T: Float + FloatConst,
+        [(); slice_ops::is_power_of(N, 2) as usize - 1]:;
+}
+
+impl<T, const N: usize> ArrayFft<T, N> for [T; N]
+{
+    fn fft<F>(&self) -> [Complex<F>; N]
+    where
+        T: Into<Complex<F>> + Copy,
+        F: Float + FloatConst,
+        [(); slice_ops::is_power_of(N, 2) as usize - 1]:
+    {
+        self.fft_radix::<2, F>()
+    }
+
+    fn ifft<F>(&self) -> [Complex<F>; N]
+    where
+        T: Into<Complex<F>> + Copy,
+        F: Float + FloatConst,
+        [(); slice_ops::is_power_of(N, 2) as usize - 1]:
+    {
+        self.ifft_radix::<2, F>()
+    }
+
+    fn fft_radix<const R: usize, F>(&self) -> [Complex<F>; N]
+    where
+        T: Into<Complex<F>> + Copy,
+        F: Float + FloatConst,
+        [(); slice_ops::is_power_of(N, R) as usize - 1]:
+    {
+        let mut y = crate::from_fn(|i| self[i].into());
+        r#impl::fft_assign::<F, N, R>(&mut y, false);
+        y
+    }
+
+    fn ifft_radix<const R: usize, F>(&self) -> [Complex<F>; N]
+    where
+        T: Into<Complex<F>> + Copy,
+        F: Float + FloatConst,
+        [(); slice_ops::is_power_of(N, R) as usize - 1]:
+    {
+        let mut y = crate::from_fn(|i| self[i].into());
+        r#impl::fft_assign::<F, N, R>(&mut y, true);
+
+        let n = F::from(N).unwrap();
+        let mut i = 0;
+        while i < N
+        {
+            y[i] = y[i].unscale(n);
+            i += 1;
+        }
+        y
+    }
+
+    fn rfft(&self) -> [Complex<T>; N/2 + 1]
+    where
+        T: Float + FloatConst,
+        [(); slice_ops::is_power_of(N, 2) as usize - 1]:
+    {
+        let y = self.fft::<T>();
+        crate::from_fn(|i| y[i])
+    }
+}
+
+mod r#impl
+{
+    use num_complex::Complex;
+    use num_traits::{Float, FloatConst};
+
+    use crate::ops::ArrayPermute;
+
+    /// In-place decimation-in-time Cooley-Tukey FFT.
+    pub(super) fn fft_assign<F, const N: usize, const R: usize>(x: &mut [Complex<F>; N], inverse: bool)
+    where
+        F: Float + FloatConst,
+        [(); slice_ops::is_power_of(N, R) as usize - 1]:
+    {
+        x.digit_rev_permutation::<R>();
+
+        let tau = if inverse {F::TAU()} else {-F::TAU()};
+
+        // Twiddle factors of the R-point butterfly
+        let w: [Complex<F>; R] = crate::from_fn(|q| Complex::cis(tau*F::from(q).unwrap()/F::from(R).unwrap()));
+
+        let mut len = R;
+        while len <= N
+        {
+            let m = len/R;
+            let mut k = 0;
+            while k < m
+            {
+                let theta = tau*F::from(k).unwrap()/F::from(len).unwrap();
+
+                let mut s = 0;
+                while s < N
+                {
+                    let y: [Complex<F>; R] = crate::from_fn(|r| x[s + k + r*m]*Complex::cis(theta*F::from(r).unwrap()));
+
+                    let mut q = 0;
+                    while q < R
+                    {
+                        let mut z = Complex::new(F::zero(), F::zero());
+                        let mut r = 0;
+                        while r < R
+                        {
+                            z = z + y[r]*w[(r*q) % R];
+                            r += 1;
+                        }
+                        x[s + k + q*m] = z;
+                        q += 1;
+                    }
+                    s += len;
+                }
+                k += 1;
+            }
+            len *= R;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use num_complex::Complex;
+
+    use crate::ops::*;
+
+    #[test]
+    fn it_works()
+    {
+        const N: usize = 16;
+
+        let x: [f64; N] = core::array::from_fn(|i| (i as f64*0.7).sin() + 0.1*i as f64);
+
+        // Naive DFT
+        let dft: [Complex<f64>; N] = core::array::from_fn(|k| x.iter()
+            .enumerate()
+            .map(|(n, &x)| Complex::cis(-core::f64::consts::TAU*(k*n) as f64/N as f64)*x)
+            .sum()
+        );
+
+        for (y, dft) in x.fft().into_iter().zip(dft)
+        {
+            assert!((y - dft).norm() < 1e-9);
+        }
+        for (y, dft) in x.fft_radix::<4, f64>().into_iter().zip(dft)
+        {
+            assert!((y - dft).norm() < 1e-9);
+        }
+        for (y, dft) in x.fft_radix::<16, f64>().into_iter().zip(dft)
+        {
+            assert!((y - dft).norm() < 1e-9);
+        }
+        for (y, dft) in x.rfft().into_iter().zip(dft)
+        {
+            assert!((y - dft).norm() < 1e-9);
+        }
+    }
+}
\ No newline at end of file
@@ -22,6 +22,53 @@ pub trait ArrayIntegrate<T, const N: usize>: Array + AsSlice<Item = T>
     fn integrate(&mut self)
     where
         T: AddAssign<T> + Copy;
+
+    /// Integrates array, starting from an integration constant (discrete calculus)
+    /// 
+    /// This is the exact inverse of [ArrayDifferentiate::differentiate_n](crate::ops::ArrayDifferentiate::differentiate_n) with `K = 1`,
+    /// given the first element of the original array as the integration constant.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [1, 2, 3];
+    /// 
+    /// assert_eq!(a.integrate_from(10), [10, 11, 13, 16]);
+    /// 
+    /// let b = [1, 4, 9, 16];
+    /// 
+    /// assert_eq!(b.differentiate_n::<1>().integrate_from(b[0]), b);
+    /// ```
+    fn integrate_from(&self, constant: T) -> [T; N + 1]
+    where
+        T: AddAssign<T> + Copy;
+
+    /// Integrates array `K` times, with one integration constant for each time (discrete calculus)
+    /// 
+    /// This is the exact inverse of [ArrayDifferentiate::differentiate_n](crate::ops::ArrayDifferentiate::differentiate_n),
+    /// given the first element of each intermediate difference as the integration constants, starting with the original array.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [1, 4, 9, 16, 25];
+    /// 
+    /// let d2 = a.differentiate_n::<2>();
+    /// 
+    /// assert_eq!(d2, [2, 2, 2]);
+    /// assert_eq!(d2.integrate_n([a[0], a[1] - a[0]]), a);
+    /// ```
+    fn integrate_n<const K: usize>(&self, constants: [T; K]) -> [T; N + K]
+    where
+        T: AddAssign<T> + Copy;
 }
 
 impl<T, const N: usize> ArrayIntegrate<T, N> for [T; N]
@@ -37,4 +84,39 @@ impl<T, const N: usize> ArrayIntegrate<T, N> for [T; N]
             i += 1;
         }
     }
+
+    fn integrate_from(&self, constant: T) -> [T; N + 1]
+    where
+        T: AddAssign<T> + Copy
+    {
+        self.integrate_n([constant])
+    }
+
+    fn integrate_n<const K: usize>(&self, constants: [T; K]) -> [T; N + K]
+    where
+        T: AddAssign<T> + Copy
+    {
+        let mut y = crate::from_fn(|i| if i < K
+        {
+            constants[i]
+        }
+        else
+        {
+            self[i - K]
+        });
+
+        let mut k = K;
+        while k > 0
+        {
+            k -= 1;
+            let mut i = k + 1;
+            while i < N + K
+            {
+                let x = y[i - 1];
+                y[i] += x;
+                i += 1;
+            }
+        }
+        y
+    }
 }
\ No newline at end of file
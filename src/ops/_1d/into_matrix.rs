@@ -1,6 +1,7 @@
 use core::{marker::Destruct, pin::Pin};
 
 use array_trait::Array;
+use num_traits::Num;
 use slice_ops::AsSlice;
 
 use crate::private::guard::PartialEmptyGuard;
@@ -127,11 +128,114 @@ pub trait ArrayIntoMatrix<T, const N: usize>: Array + AsSlice<Item = T>
     fn toeplitz_matrix_ref(&self) -> [[&T; N]; N];
     fn toeplitz_matrix_pin_ref(self: Pin<&Self>) -> [[Pin<&T>; N]; N];
 
+    /// Creates a Hankel matrix with the array as its first collumn and `r` as its last row.
+    /// 
+    /// The first element of `r` is ignored, as the corner is taken from the array.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let c = [1, 2, 3];
+    /// let r = [0, 4, 5, 6];
+    /// 
+    /// assert_eq!(c.hankel_matrix(&r), [
+    ///     [1, 2, 3, 4],
+    ///     [2, 3, 4, 5],
+    ///     [3, 4, 5, 6]
+    /// ]);
+    /// ```
     fn hankel_matrix<const M: usize>(&self, r: &[T; M]) -> [[T; M]; N]
     where
         T: Copy;
     fn hankel_matrix_ref<'a, const M: usize>(&'a self, r: &'a [T; M]) -> [[&'a T; M]; N];
     fn hankel_matrix_pin_ref<'a, const M: usize>(self: Pin<&'a Self>, r: Pin<&'a [T; M]>) -> [[Pin<&'a T>; M]; N];
+
+    /// Creates a Toeplitz matrix with the array as its first collumn and `r` as its first row.
+    /// 
+    /// The first element of `r` is ignored, as the corner is taken from the array.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let c = [1, 2, 3];
+    /// let r = [0, 4, 5, 6];
+    /// 
+    /// assert_eq!(c.toeplitz_rect_matrix(&r), [
+    ///     [1, 4, 5, 6],
+    ///     [2, 1, 4, 5],
+    ///     [3, 2, 1, 4]
+    /// ]);
+    /// ```
+    fn toeplitz_rect_matrix<const M: usize>(&self, r: &[T; M]) -> [[T; M]; N]
+    where
+        T: Copy;
+    fn toeplitz_rect_matrix_ref<'a, const M: usize>(&'a self, r: &'a [T; M]) -> [[&'a T; M]; N];
+    fn toeplitz_rect_matrix_pin_ref<'a, const M: usize>(self: Pin<&'a Self>, r: Pin<&'a [T; M]>) -> [[Pin<&'a T>; M]; N];
+
+    /// Creates a circulant matrix, where each row is the previous one rotated one step to the right.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [1, 2, 3];
+    /// 
+    /// assert_eq!(a.circulant_matrix(), [
+    ///     [1, 2, 3],
+    ///     [3, 1, 2],
+    ///     [2, 3, 1]
+    /// ]);
+    /// ```
+    fn circulant_matrix(&self) -> [[T; N]; N]
+    where
+        T: Copy;
+    fn circulant_matrix_ref(&self) -> [[&T; N]; N];
+    fn circulant_matrix_pin_ref(self: Pin<&Self>) -> [[Pin<&T>; N]; N];
+
+    /// Creates a Vandermonde matrix, where each row holds the powers `0..M` of the corresponding element.
+    /// 
+    /// The powers are new values rather than elements of the array, so there is no `_ref` variant.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let x = [1, 2, 3];
+    /// 
+    /// assert_eq!(x.vandermonde_matrix::<4>(), [
+    ///     [1, 1, 1, 1],
+    ///     [1, 2, 4, 8],
+    ///     [1, 3, 9, 27]
+    /// ]);
+    /// ```
+    fn vandermonde_matrix<const M: usize>(&self) -> [[T; M]; N]
+    where
+        T: Num + Copy;
+
+    /// Creates an identity matrix.
+    /// 
+    /// This doesn't read any array at all, so there is nothing for a `_ref` variant to borrow.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// assert_eq!(<[f64; 3]>::identity_matrix(), [
+    ///     [1.0, 0.0, 0.0],
+    ///     [0.0, 1.0, 0.0],
+    ///     [0.0, 0.0, 1.0]
+    /// ]);
+    /// ```
+    fn identity_matrix() -> [[T; N]; N]
+    where
+        T: Num + Copy;
 }
 
 impl<T, const N: usize> ArrayIntoMatrix<T, N> for [T; N]
@@ -354,11 +458,63 @@ impl<T, const N: usize> ArrayIntoMatrix<T, N> for [T; N]
     {
         r#impl::hankel_matrix(&self, &r)
     }
+
+    fn toeplitz_rect_matrix<const M: usize>(&self, r: &[T; M]) -> [[T; M]; N]
+    where
+        T: Copy
+    {
+        r#impl::toeplitz_rect_matrix(self, r)
+    }
+    fn toeplitz_rect_matrix_ref<'a, const M: usize>(&'a self, r: &'a [T; M]) -> [[&'a T; M]; N]
+    {
+        r#impl::toeplitz_rect_matrix(&self, &r)
+    }
+    fn toeplitz_rect_matrix_pin_ref<'a, const M: usize>(self: Pin<&'a Self>, r: Pin<&'a [T; M]>) -> [[Pin<&'a T>; M]; N]
+    {
+        r#impl::toeplitz_rect_matrix(&self, &r)
+    }
+
+    fn circulant_matrix(&self) -> [[T; N]; N]
+    where
+        T: Copy
+    {
+        r#impl::circulant_matrix(self)
+    }
+    fn circulant_matrix_ref(&self) -> [[&T; N]; N]
+    {
+        r#impl::circulant_matrix(&self)
+    }
+    fn circulant_matrix_pin_ref(self: Pin<&Self>) -> [[Pin<&T>; N]; N]
+    {
+        r#impl::circulant_matrix(&self)
+    }
+
+    fn vandermonde_matrix<const M: usize>(&self) -> [[T; M]; N]
+    where
+        T: Num + Copy
+    {
+        crate::from_fn(|i| {
+            let x = self[i];
+            let mut p = T::one();
+            crate::from_fn(|_| {
+                let y = p;
+                p = p*x;
+                y
+            })
+        })
+    }
+
+    fn identity_matrix() -> [[T; N]; N]
+    where
+        T: Num + Copy
+    {
+        [T::one(); N].diagonal_or_matrix_exact(T::zero())
+    }
 }
 
 mod r#impl
 {
-    use crate::form::ArrayForm;
+    use crate::{form::ArrayForm, ops::ArrayRotate};
 
     use super::PartialEmptyGuard;
 
@@ -447,6 +603,29 @@ mod r#impl
         crate::from_fn(|i| crate::from_fn(|j| array.copy_elem(if i >= j {i - j} else {j - i})))
     }
 
+    pub(super) fn toeplitz_rect_matrix<const N: usize, const M: usize, A, B>(array: &A, r: &B) -> [[A::Elem; M]; N]
+    where
+        A: ArrayForm<N, Elem: Copy>,
+        B: ArrayForm<M, Elem = A::Elem>
+    {
+        crate::from_fn(|i| crate::from_fn(|j| if i >= j
+        {
+            array.copy_elem(i - j)
+        }
+        else
+        {
+            r.copy_elem(j - i)
+        }))
+    }
+
+    pub(super) fn circulant_matrix<const N: usize, A>(array: &A) -> [[A::Elem; N]; N]
+    where
+        A: ArrayForm<N, Elem: Copy>
+    {
+        let row: [A::Elem; N] = crate::from_fn(|j| array.copy_elem(j));
+        crate::from_fn(|i| row.into_rotate_right(i))
+    }
+
     pub(super) fn hankel_matrix<const N: usize, const M: usize, A, B>(array: &A, r: &B) -> [[A::Elem; M]; N]
     where
         A: ArrayForm<N, Elem: Copy>,
@@ -0,0 +1,132 @@
+use core::{cmp::Ordering, marker::Destruct};
+
+use array_trait::Array;
+use slice_ops::AsSlice;
+
+use super::ArrayChain;
+
+#[const_trait]
+pub trait ArrayMergeSorted<T, const N: usize>: Array + AsSlice<Item = T>
+{
+    /// Merges two sorted arrays into one sorted array.
+    /// 
+    /// The merge is stable, so for equal elements, those from `self` come first.
+    /// 
+    /// If `T`'s ordering panics, every element is still dropped exactly once.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [1, 3, 5, 7];
+    /// let b = [2, 3, 8];
+    /// 
+    /// assert_eq!(a.merge_sorted(b), [1, 2, 3, 3, 5, 7, 8]);
+    /// ```
+    fn merge_sorted<const M: usize>(self, rhs: [T; M]) -> [T; N + M]
+    where
+        T: Ord;
+    /// Merges two arrays, sorted with the same comparator function, into one sorted array.
+    /// 
+    /// The merge is stable, so for equal elements, those from `self` come first.
+    /// 
+    /// If the comparator panics, every element is still dropped exactly once.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = ["fig", "pear", "banana"];
+    /// let b = ["kiwi", "cherry"];
+    /// 
+    /// assert_eq!(
+    ///     a.merge_sorted_by(b, |a, b| a.len().cmp(&b.len())),
+    ///     ["fig", "pear", "kiwi", "banana", "cherry"]
+    /// );
+    /// ```
+    fn merge_sorted_by<F, const M: usize>(self, rhs: [T; M], compare: F) -> [T; N + M]
+    where
+        F: FnMut(&T, &T) -> Ordering + ~const Destruct;
+}
+
+impl<T, const N: usize> ArrayMergeSorted<T, N> for [T; N]
+{
+    fn merge_sorted<const M: usize>(self, rhs: [T; M]) -> [T; N + M]
+    where
+        T: Ord
+    {
+        self.merge_sorted_by(rhs, Ord::cmp)
+    }
+    fn merge_sorted_by<F, const M: usize>(self, rhs: [T; M], mut compare: F) -> [T; N + M]
+    where
+        F: FnMut(&T, &T) -> Ordering
+    {
+        let mut a = self.chain(rhs);
+
+        // Merge the runs [i, mid) and [mid, N + M) in-place. Each block of right-hand elements smaller than a[i]
+        // is rotated in front of it, so equal elements keep their left-before-right order.
+        let mut i = 0;
+        let mut mid = N;
+        while i < mid && mid < N + M
+        {
+            let mut j = mid;
+            while j < N + M && compare(&a[j], &a[i]).is_lt()
+            {
+                j += 1;
+            }
+            if j > mid
+            {
+                a[i..j].rotate_right(j - mid);
+                i += j - mid;
+                mid = j;
+            }
+            i += 1;
+        }
+
+        a
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::ops::*;
+
+    #[test]
+    fn it_works()
+    {
+        let lhs: [[i32; 5]; 4] = [
+            [0, 1, 2, 3, 4],
+            [5, 6, 7, 8, 9],
+            [1, 1, 1, 1, 1],
+            [-3, 0, 0, 4, 7]
+        ];
+        let rhs: [[i32; 3]; 4] = [
+            [5, 6, 7],
+            [0, 1, 2],
+            [1, 1, 1],
+            [-3, 0, 8]
+        ];
+        for a in lhs
+        {
+            for b in rhs
+            {
+                assert_eq!(a.merge_sorted(b), a.chain(b).sorted());
+            }
+        }
+
+        assert_eq!([1, 2].merge_sorted([] as [i32; 0]), [1, 2]);
+        assert_eq!(([] as [i32; 0]).merge_sorted([1, 2]), [1, 2]);
+
+        // Stability
+        let a = [(0, 'a'), (1, 'b'), (1, 'c')];
+        let b = [(1, 'd'), (2, 'e')];
+        assert_eq!(a.merge_sorted_by(b, |a, b| a.0.cmp(&b.0)), [(0, 'a'), (1, 'b'), (1, 'c'), (1, 'd'), (2, 'e')]);
+    }
+}
\ No newline at end of file
@@ -4,14 +4,18 @@ moddef::moddef!(
         add,
         argminmax,
         argreduce,
+        big_int,
         bitand_assign,
         bitand,
         bitor_assign,
         bitor,
         bitxor_assign,
         bitxor,
+        cartesian_product,
         chain,
         chunks,
+        compensated_sum,
+        convolve,
         differentiate,
         div_assign,
         div,
@@ -29,6 +33,7 @@ moddef::moddef!(
         enumerate_zip_with,
         enumerate,
         extend,
+        fft,
         find,
         flatmap,
         fold,
@@ -46,6 +51,7 @@ moddef::moddef!(
         map_outer,
         map,
         meet,
+        merge_sorted,
         minmax,
         mul_assign,
         mul_cross,
@@ -54,31 +60,43 @@ moddef::moddef!(
         mul,
         neg_assign,
         neg,
+        next_permutation,
+        norm,
         not_assign,
         not,
         permute,
+        poly,
         product,
         proj,
+        quadrature,
         reduce,
         reformulate,
         rem_assign,
         rem,
         resize,
         rotate,
+        select,
         shift,
         shl_assign,
         shl,
         shr_assign,
         shr,
+        shuffle,
         simd,
+        sort_network,
+        sort,
+        sorted_set,
         split,
         spread,
+        statistics,
         sub_assign,
         sub,
         sum,
         truncate,
         unsimd,
         visit,
+        walsh_hadamard,
+        windows,
         zip_assign_with,
         zip_outer_with,
         zip_outer,
@@ -0,0 +1,162 @@
+use core::{cmp::Ordering, marker::Destruct};
+
+use array_trait::Array;
+use slice_ops::AsSlice;
+
+#[const_trait]
+pub trait ArrayNextPermutation<T, const N: usize>: Array + AsSlice<Item = T>
+{
+    /// Rearranges the array into the next lexicographically greater permutation.
+    /// 
+    /// Returns `false` if the array was already the greatest permutation, in which case it wraps around to the smallest one, i.e. is sorted in ascending order.
+    /// 
+    /// Repeated elements are treated as indistinguishable, so each distinct permutation is visited once.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let mut a = [1, 2, 3];
+    /// 
+    /// assert!(a.next_permutation());
+    /// assert_eq!(a, [1, 3, 2]);
+    /// assert!(a.next_permutation());
+    /// assert_eq!(a, [2, 1, 3]);
+    /// 
+    /// let mut a = [3, 2, 1];
+    /// 
+    /// assert!(!a.next_permutation());
+    /// assert_eq!(a, [1, 2, 3]);
+    /// ```
+    fn next_permutation(&mut self) -> bool
+    where
+        T: Ord;
+    /// Rearranges the array into the next lexicographically greater permutation, with a comparator function.
+    /// 
+    /// Returns `false` if the array was already the greatest permutation, in which case it wraps around to the smallest one.
+    fn next_permutation_by<F>(&mut self, compare: F) -> bool
+    where
+        F: FnMut(&T, &T) -> Ordering + ~const Destruct;
+    /// Rearranges the array into the previous lexicographically smaller permutation.
+    /// 
+    /// Returns `false` if the array was already the smallest permutation, in which case it wraps around to the greatest one, i.e. is sorted in descending order.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let mut a = [2, 1, 3];
+    /// 
+    /// assert!(a.prev_permutation());
+    /// assert_eq!(a, [1, 3, 2]);
+    /// assert!(a.prev_permutation());
+    /// assert_eq!(a, [1, 2, 3]);
+    /// assert!(!a.prev_permutation());
+    /// assert_eq!(a, [3, 2, 1]);
+    /// ```
+    fn prev_permutation(&mut self) -> bool
+    where
+        T: Ord;
+    /// Rearranges the array into the previous lexicographically smaller permutation, with a comparator function.
+    /// 
+    /// Returns `false` if the array was already the smallest permutation, in which case it wraps around to the greatest one.
+    fn prev_permutation_by<F>(&mut self, compare: F) -> bool
+    where
+        F: FnMut(&T, &T) -> Ordering + ~const Destruct;
+}
+
+impl<T, const N: usize> ArrayNextPermutation<T, N> for [T; N]
+{
+    fn next_permutation(&mut self) -> bool
+    where
+        T: Ord
+    {
+        r#impl::next_permutation_by(self, Ord::cmp)
+    }
+    fn next_permutation_by<F>(&mut self, mut compare: F) -> bool
+    where
+        F: FnMut(&T, &T) -> Ordering
+    {
+        r#impl::next_permutation_by(self, |a, b| compare(a, b))
+    }
+    fn prev_permutation(&mut self) -> bool
+    where
+        T: Ord
+    {
+        r#impl::next_permutation_by(self, |a, b| b.cmp(a))
+    }
+    fn prev_permutation_by<F>(&mut self, mut compare: F) -> bool
+    where
+        F: FnMut(&T, &T) -> Ordering
+    {
+        r#impl::next_permutation_by(self, |a, b| compare(b, a))
+    }
+}
+
+mod r#impl
+{
+    use core::cmp::Ordering;
+
+    pub(super) fn next_permutation_by<T, F>(slice: &mut [T], mut compare: F) -> bool
+    where
+        F: FnMut(&T, &T) -> Ordering
+    {
+        let n = slice.len();
+        if n < 2
+        {
+            return false
+        }
+
+        // Find the start of the longest non-increasing suffix
+        let mut i = n - 1;
+        while i > 0 && compare(&slice[i - 1], &slice[i]) != Ordering::Less
+        {
+            i -= 1;
+        }
+        if i == 0
+        {
+            slice.reverse();
+            return false
+        }
+
+        // Swap the pivot with the rightmost element greater than it
+        let mut j = n - 1;
+        while compare(&slice[i - 1], &slice[j]) != Ordering::Less
+        {
+            j -= 1;
+        }
+        slice.swap(i - 1, j);
+        slice[i..].reverse();
+        true
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::ops::*;
+
+    #[test]
+    fn it_works()
+    {
+        let mut a = [1, 1, 2, 3];
+        let mut count = 1;
+        while a.next_permutation()
+        {
+            count += 1;
+        }
+        assert_eq!(count, 12);
+        assert_eq!(a, [1, 1, 2, 3]);
+
+        let mut a = [0, 1, 2, 3];
+        let mut b = a;
+        assert!(a.next_permutation());
+        assert!(a.prev_permutation());
+        assert_eq!(a, b);
+
+        assert!(!b.next_permutation_by(|x, y| y.cmp(x)));
+        assert_eq!(b, [3, 2, 1, 0]);
+    }
+}
\ No newline at end of file
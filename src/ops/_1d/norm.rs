@@ -0,0 +1,331 @@
+use num_traits::Float;
+
+use super::ArrayPartialMagnitude;
+
+#[const_trait]
+pub trait ArrayNorm<T, const N: usize>: ArrayPartialMagnitude<T, N>
+{
+    /// Computes the euclidean length (L2-norm).
+    /// 
+    /// The elements are scaled before squaring, so this does not overflow unless the result does.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// assert_eq!([3.0, 4.0].magnitude(), 5.0);
+    /// assert_eq!([3e300, 4e300].magnitude(), 5e300);
+    /// ```
+    fn magnitude(&self) -> T
+    where
+        T: Float;
+
+    /// Computes the sum of absolute values (L1-norm).
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// assert_eq!([3.0, -4.0].norm_l1(), 7.0);
+    /// ```
+    fn norm_l1(&self) -> T
+    where
+        T: Float;
+
+    /// Computes the largest absolute value (L∞-norm).
+    /// 
+    /// If any element is NaN, the result is NaN.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// assert_eq!([3.0, -4.0].norm_linf(), 4.0);
+    /// assert!([f64::NAN, 1.0].norm_linf().is_nan());
+    /// ```
+    fn norm_linf(&self) -> T
+    where
+        T: Float;
+
+    /// Computes the Lp-norm.
+    /// 
+    /// The elements are scaled before exponentiation, so this does not overflow unless the result does.
+    /// 
+    /// A `p` of positive infinity gives the largest absolute value, and a `p` of negative infinity gives the smallest.
+    /// Otherwise, `p` must be positive. It is only a true norm for `p >= 1`, and a quasi-norm below that.
+    /// 
+    /// # Panics
+    /// 
+    /// In debug builds, panics if `p` is NaN, zero or a finite negative number.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [3.0, -4.0];
+    /// 
+    /// assert_eq!(a.norm_p(1.0), a.norm_l1());
+    /// assert_eq!(a.norm_p(2.0), a.magnitude());
+    /// assert_eq!(a.norm_p(f64::INFINITY), a.norm_linf());
+    /// assert_eq!(a.norm_p(f64::NEG_INFINITY), 3.0);
+    /// ```
+    fn norm_p(&self, p: T) -> T
+    where
+        T: Float;
+
+    /// Scales the array to unit length.
+    /// 
+    /// If the array has zero magnitude, the result is NaN.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let mut a = [3.0, 4.0];
+    /// 
+    /// a.normalize();
+    /// 
+    /// assert_eq!(a, [0.6, 0.8]);
+    /// ```
+    fn normalize(&mut self)
+    where
+        T: Float;
+    /// Returns the array scaled to unit length.
+    /// 
+    /// If the array has zero magnitude, the result is NaN.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// assert_eq!([0.0, -2.0].normalized(), [0.0, -1.0]);
+    /// ```
+    fn normalized(&self) -> [T; N]
+    where
+        T: Float;
+
+    /// Computes the euclidean distance to another array.
+    /// 
+    /// The differences are scaled before squaring, so this does not overflow unless the result does.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// assert_eq!([1.0, 1.0].distance(&[4.0, 5.0]), 5.0);
+    /// ```
+    fn distance(&self, other: &[T; N]) -> T
+    where
+        T: Float;
+    /// Computes the squared euclidean distance to another array.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// assert_eq!([1.0, 1.0].distance_squared(&[4.0, 5.0]), 25.0);
+    /// ```
+    fn distance_squared(&self, other: &[T; N]) -> T
+    where
+        T: Float;
+
+    /// Computes the angle between two arrays, in radians.
+    /// 
+    /// This is accurate also for nearly parallel arrays. If either array has zero length, the result is NaN.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use core::f64::consts::FRAC_PI_2;
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// assert_eq!([1.0, 0.0].angle_between(&[0.0, 3.0]), FRAC_PI_2);
+    /// assert_eq!([1.0, 1.0].angle_between(&[2.0, 2.0]), 0.0);
+    /// ```
+    fn angle_between(&self, other: &[T; N]) -> T
+    where
+        T: Float;
+}
+
+impl<T, const N: usize> ArrayNorm<T, N> for [T; N]
+{
+    fn magnitude(&self) -> T
+    where
+        T: Float
+    {
+        r#impl::scaled_norm_l2(self)
+    }
+
+    fn norm_l1(&self) -> T
+    where
+        T: Float
+    {
+        let mut y = T::zero();
+        let mut i = 0;
+        while i < N
+        {
+            y = y + self[i].abs();
+            i += 1;
+        }
+        y
+    }
+
+    fn norm_linf(&self) -> T
+    where
+        T: Float
+    {
+        r#impl::max_abs(self)
+    }
+
+    fn norm_p(&self, p: T) -> T
+    where
+        T: Float
+    {
+        debug_assert!(p > T::zero() || p == T::neg_infinity(), "p must be positive or negative infinity");
+
+        if p == T::neg_infinity()
+        {
+            return r#impl::min_abs(self)
+        }
+        let scale = r#impl::max_abs(self);
+        if p.is_infinite() || scale.is_zero() || scale.is_infinite()
+        {
+            return scale
+        }
+
+        let mut y = T::zero();
+        let mut i = 0;
+        while i < N
+        {
+            y = y + (self[i].abs()/scale).powf(p);
+            i += 1;
+        }
+        scale*y.powf(p.recip())
+    }
+
+    fn normalize(&mut self)
+    where
+        T: Float
+    {
+        let r = self.magnitude();
+        let mut i = 0;
+        while i < N
+        {
+            self[i] = self[i]/r;
+            i += 1;
+        }
+    }
+    fn normalized(&self) -> [T; N]
+    where
+        T: Float
+    {
+        let mut y = *self;
+        y.normalize();
+        y
+    }
+
+    fn distance(&self, other: &[T; N]) -> T
+    where
+        T: Float
+    {
+        r#impl::scaled_norm_l2::<T, N>(&crate::from_fn(|i| self[i] - other[i]))
+    }
+    fn distance_squared(&self, other: &[T; N]) -> T
+    where
+        T: Float
+    {
+        let mut y = T::zero();
+        let mut i = 0;
+        while i < N
+        {
+            let d = self[i] - other[i];
+            y = y + d*d;
+            i += 1;
+        }
+        y
+    }
+
+    fn angle_between(&self, other: &[T; N]) -> T
+    where
+        T: Float
+    {
+        // Kahan's formula, which does not lose precision for small angles like acos does
+        let a = self.normalized();
+        let b = other.normalized();
+        let sum = r#impl::scaled_norm_l2::<T, N>(&crate::from_fn(|i| a[i] + b[i]));
+        let diff = r#impl::scaled_norm_l2::<T, N>(&crate::from_fn(|i| a[i] - b[i]));
+        (T::one() + T::one())*diff.atan2(sum)
+    }
+}
+
+mod r#impl
+{
+    use num_traits::Float;
+
+    pub(super) fn max_abs<T, const N: usize>(x: &[T; N]) -> T
+    where
+        T: Float
+    {
+        let mut y = T::zero();
+        let mut i = 0;
+        while i < N
+        {
+            // Float::max discards NaN, so it has to be propagated by hand
+            if x[i].is_nan()
+            {
+                return x[i]
+            }
+            y = y.max(x[i].abs());
+            i += 1;
+        }
+        y
+    }
+
+    pub(super) fn min_abs<T, const N: usize>(x: &[T; N]) -> T
+    where
+        T: Float
+    {
+        let mut y = T::infinity();
+        let mut i = 0;
+        while i < N
+        {
+            if x[i].is_nan()
+            {
+                return x[i]
+            }
+            y = y.min(x[i].abs());
+            i += 1;
+        }
+        y
+    }
+
+    pub(super) fn scaled_norm_l2<T, const N: usize>(x: &[T; N]) -> T
+    where
+        T: Float
+    {
+        let scale = max_abs(x);
+        if scale.is_zero() || scale.is_infinite()
+        {
+            return scale
+        }
+
+        let mut y = T::zero();
+        let mut i = 0;
+        while i < N
+        {
+            let x = x[i]/scale;
+            y = y + x*x;
+            i += 1;
+        }
+        scale*y.sqrt()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,288 @@
+use core::ops::AddAssign;
+
+use array_trait::Array;
+use num_traits::Num;
+use slice_ops::AsSlice;
+
+use super::{ArrayConvolve, ArrayResize};
+
+/// Polynomial arithmetic, where the coefficients are stored in ascending order of degree, i.e. `c[0] + c[1]*x + c[2]*x^2 + ...`.
+#[const_trait]
+pub trait ArrayPoly<T, const N: usize>: Array + AsSlice<Item = T>
+{
+    /// Evaluates the polynomial at `x`, using Horner's method.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// // 1 + 2x + 3x²
+    /// let p = [1, 2, 3];
+    /// 
+    /// assert_eq!(p.poly_eval(2), 17);
+    /// ```
+    fn poly_eval(&self, x: T) -> T
+    where
+        T: Num + Copy;
+
+    /// Adds two polynomials.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let p = [1, 2, 3];
+    /// let q = [4, 5];
+    /// 
+    /// assert_eq!(p.poly_add(q), [5, 7, 3]);
+    /// ```
+    fn poly_add<const M: usize>(self, rhs: [T; M]) -> [T; crate::max_len(N, M)]
+    where
+        T: Num + Copy,
+        [(); crate::max_len(N, M)]:;
+    /// Subtracts two polynomials.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let p = [1, 2];
+    /// let q = [4, 5, 6];
+    /// 
+    /// assert_eq!(p.poly_sub(q), [-3, -3, -6]);
+    /// ```
+    fn poly_sub<const M: usize>(self, rhs: [T; M]) -> [T; crate::max_len(N, M)]
+    where
+        T: Num + Copy,
+        [(); crate::max_len(N, M)]:;
+    /// Multiplies two polynomials.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// // (1 + x)(1 - x + x²) = 1 + x³
+    /// let p = [1, 1];
+    /// let q = [1, -1, 1];
+    /// 
+    /// assert_eq!(p.poly_mul(q), [1, 0, 0, 1]);
+    /// ```
+    fn poly_mul<const M: usize>(self, rhs: [T; M]) -> [T; N + M - 1]
+    where
+        T: Num + AddAssign + Default + Copy,
+        [(); N + M - 1]:;
+    /// Divides two polynomials, returning the quotient and remainder.
+    /// 
+    /// Returns `None` if the leading coefficient of the divisor is zero. For integer coefficients, the division is only exact if the leading coefficient of the divisor divides the ones it's applied to.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// // x³ - 2x² - 4 = (x - 3)(x² + x + 3) + 5
+    /// let p = [-4.0, 0.0, -2.0, 1.0];
+    /// let q = [-3.0, 1.0];
+    /// 
+    /// let (quotient, remainder) = p.poly_div_rem(q).unwrap();
+    /// 
+    /// assert_eq!(quotient, [3.0, 1.0, 1.0]);
+    /// assert_eq!(remainder, [5.0]);
+    /// ```
+    fn poly_div_rem<const M: usize>(self, rhs: [T; M]) -> Option<([T; N - M + 1], [T; M - 1])>
+    where
+        T: Num + Copy,
+        [(); N - M + 1]:,
+        [(); M - 1]:;
+
+    /// Differentiates the polynomial.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// // d/dx (1 + 2x + 3x²) = 2 + 6x
+    /// let p = [1, 2, 3];
+    /// 
+    /// assert_eq!(p.poly_derivative(), [2, 6]);
+    /// ```
+    fn poly_derivative(self) -> [T; N - 1]
+    where
+        T: Num + Copy,
+        [(); N - 1]:;
+    /// Integrates the polynomial, with the given constant of integration.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// // ∫ (2 + 6x) dx = c + 2x + 3x²
+    /// let p = [2.0, 6.0];
+    /// 
+    /// assert_eq!(p.poly_integral(1.0), [1.0, 2.0, 3.0]);
+    /// ```
+    fn poly_integral(self, constant: T) -> [T; N + 1]
+    where
+        T: Num + Copy,
+        [(); N + 1]:;
+}
+
+impl<T, const N: usize> ArrayPoly<T, N> for [T; N]
+{
+    fn poly_eval(&self, x: T) -> T
+    where
+        T: Num + Copy
+    {
+        let mut y = T::zero();
+        let mut i = N;
+        while i > 0
+        {
+            i -= 1;
+            y = y*x + self[i];
+        }
+        y
+    }
+
+    fn poly_add<const M: usize>(self, rhs: [T; M]) -> [T; crate::max_len(N, M)]
+    where
+        T: Num + Copy,
+        [(); crate::max_len(N, M)]:
+    {
+        let mut sum: [T; crate::max_len(N, M)] = self.resize(|_| T::zero());
+        let mut i = 0;
+        while i < M
+        {
+            sum[i] = sum[i] + rhs[i];
+            i += 1;
+        }
+        sum
+    }
+    fn poly_sub<const M: usize>(self, rhs: [T; M]) -> [T; crate::max_len(N, M)]
+    where
+        T: Num + Copy,
+        [(); crate::max_len(N, M)]:
+    {
+        let mut difference: [T; crate::max_len(N, M)] = self.resize(|_| T::zero());
+        let mut i = 0;
+        while i < M
+        {
+            difference[i] = difference[i] - rhs[i];
+            i += 1;
+        }
+        difference
+    }
+    fn poly_mul<const M: usize>(self, rhs: [T; M]) -> [T; N + M - 1]
+    where
+        T: Num + AddAssign + Default + Copy,
+        [(); N + M - 1]:
+    {
+        <Self as ArrayConvolve<T, N>>::convolve::<[T; M], M>(&self, &rhs)
+    }
+    fn poly_div_rem<const M: usize>(self, rhs: [T; M]) -> Option<([T; N - M + 1], [T; M - 1])>
+    where
+        T: Num + Copy,
+        [(); N - M + 1]:,
+        [(); M - 1]:
+    {
+        let lead = rhs[M - 1];
+        if lead.is_zero()
+        {
+            return None
+        }
+
+        // Long division, eliminating the highest remaining coefficient each step
+        let mut remainder = self;
+        let mut quotient = [T::zero(); N - M + 1];
+        let mut k = N - M + 1;
+        while k > 0
+        {
+            k -= 1;
+            let q = remainder[k + M - 1]/lead;
+            quotient[k] = q;
+            let mut j = 0;
+            while j < M
+            {
+                remainder[k + j] = remainder[k + j] - q*rhs[j];
+                j += 1;
+            }
+        }
+
+        // Only the lowest M - 1 coefficients can be non-zero
+        Some((quotient, remainder.resize(|_| T::zero())))
+    }
+
+    fn poly_derivative(self) -> [T; N - 1]
+    where
+        T: Num + Copy,
+        [(); N - 1]:
+    {
+        let mut derivative: [T; N - 1] = self.rresize(|_| T::zero());
+        let mut k = T::zero();
+        let mut i = 0;
+        while i < N - 1
+        {
+            k = k + T::one();
+            derivative[i] = derivative[i]*k;
+            i += 1;
+        }
+        derivative
+    }
+    fn poly_integral(self, constant: T) -> [T; N + 1]
+    where
+        T: Num + Copy,
+        [(); N + 1]:
+    {
+        let mut integral: [T; N + 1] = self.rresize(|_| constant);
+        let mut k = T::zero();
+        let mut i = 1;
+        while i < N + 1
+        {
+            k = k + T::one();
+            integral[i] = integral[i]/k;
+            i += 1;
+        }
+        integral
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::ops::*;
+
+    #[test]
+    fn it_works()
+    {
+        let p = [2.0, -3.0, 0.0, 1.0];
+        let q = [1.0, 1.0];
+
+        let pq = p.poly_mul(q);
+        let (quotient, remainder) = pq.poly_div_rem(q).unwrap();
+        assert_eq!(quotient, p);
+        assert_eq!(remainder, [0.0]);
+
+        assert_eq!(p.poly_integral(0.0).poly_derivative(), p);
+        assert_eq!(p.poly_add(q).poly_sub(q), p);
+        assert_eq!(pq.poly_eval(2.0), p.poly_eval(2.0)*q.poly_eval(2.0));
+
+        assert!([1.0, 0.0].poly_div_rem([1.0, 0.0]).is_none());
+    }
+}
\ No newline at end of file
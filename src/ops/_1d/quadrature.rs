@@ -0,0 +1,248 @@
+use array_trait::Array;
+use num_traits::Float;
+use slice_ops::AsSlice;
+
+#[const_trait]
+pub trait ArrayQuadrature<T, const N: usize>: Array + AsSlice<Item = T>
+{
+    /// Integrates uniformly spaced samples using the trapezoidal rule.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let y = [0.0, 1.0, 4.0, 9.0];
+    /// 
+    /// assert_eq!(y.trapz(1.0), 9.5);
+    /// ```
+    fn trapz(&self, dx: T) -> T
+    where
+        T: Float;
+    /// Integrates samples at the given positions using the trapezoidal rule.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let x = [0.0, 1.0, 3.0];
+    /// let y = [0.0, 1.0, 3.0];
+    /// 
+    /// assert_eq!(y.trapz_x(&x), 4.5);
+    /// ```
+    fn trapz_x(&self, x: &[T; N]) -> T
+    where
+        T: Float;
+
+    /// Integrates uniformly spaced samples using composite Simpson's rule. Length must be odd.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let y = [0.0, 1.0, 4.0, 9.0, 16.0];
+    /// 
+    /// // Exact for polynomials up to third degree
+    /// assert_eq!(y.simpson(1.0), 64.0/3.0);
+    /// ```
+    fn simpson(&self, dx: T) -> T
+    where
+        T: Float,
+        [(); N % 2 - 1]:;
+    /// Integrates samples at the given positions using composite Simpson's rule. Length must be odd.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let x = [0.0, 1.0, 3.0];
+    /// let y = x.map(|x| x*x);
+    /// 
+    /// assert_eq!(y.simpson_x(&x), 9.0);
+    /// ```
+    fn simpson_x(&self, x: &[T; N]) -> T
+    where
+        T: Float,
+        [(); N % 2 - 1]:;
+
+    /// Cumulatively integrates uniformly spaced samples using the trapezoidal rule, starting at zero.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let y = [0.0, 1.0, 4.0, 9.0];
+    /// 
+    /// assert_eq!(y.cumtrapz(1.0), [0.0, 0.5, 3.0, 9.5]);
+    /// ```
+    fn cumtrapz(&self, dx: T) -> [T; N]
+    where
+        T: Float;
+    /// Cumulatively integrates samples at the given positions using the trapezoidal rule, starting at zero.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let x = [0.0, 1.0, 3.0];
+    /// let y = [0.0, 1.0, 3.0];
+    /// 
+    /// assert_eq!(y.cumtrapz_x(&x), [0.0, 0.5, 4.5]);
+    /// ```
+    fn cumtrapz_x(&self, x: &[T; N]) -> [T; N]
+    where
+        T: Float;
+}
+
+impl<T, const N: usize> ArrayQuadrature<T, N> for [T; N]
+{
+    fn trapz(&self, dx: T) -> T
+    where
+        T: Float
+    {
+        if N < 2
+        {
+            return T::zero()
+        }
+        let two = T::one() + T::one();
+
+        let mut sum = (self[0] + self[N - 1])/two;
+        let mut i = 1;
+        while i < N - 1
+        {
+            sum = sum + self[i];
+            i += 1;
+        }
+        sum*dx
+    }
+    fn trapz_x(&self, x: &[T; N]) -> T
+    where
+        T: Float
+    {
+        let two = T::one() + T::one();
+
+        let mut sum = T::zero();
+        let mut i = 1;
+        while i < N
+        {
+            sum = sum + (x[i] - x[i - 1])*(self[i] + self[i - 1])/two;
+            i += 1;
+        }
+        sum
+    }
+
+    fn simpson(&self, dx: T) -> T
+    where
+        T: Float,
+        [(); N % 2 - 1]:
+    {
+        if N < 3
+        {
+            return T::zero()
+        }
+        let two = T::one() + T::one();
+        let three = two + T::one();
+        let four = two + two;
+
+        let mut sum = self[0] + self[N - 1];
+        let mut i = 1;
+        while i < N - 1
+        {
+            sum = sum + self[i]*if i % 2 == 1 {four} else {two};
+            i += 1;
+        }
+        sum*dx/three
+    }
+    fn simpson_x(&self, x: &[T; N]) -> T
+    where
+        T: Float,
+        [(); N % 2 - 1]:
+    {
+        let two = T::one() + T::one();
+        let six = two + two + two;
+
+        let mut sum = T::zero();
+        let mut i = 2;
+        while i < N
+        {
+            let h0 = x[i - 1] - x[i - 2];
+            let h1 = x[i] - x[i - 1];
+            let h = h0 + h1;
+
+            sum = sum + h/six*(
+                (two - h1/h0)*self[i - 2]
+                + h*h/(h0*h1)*self[i - 1]
+                + (two - h0/h1)*self[i]
+            );
+            i += 2;
+        }
+        sum
+    }
+
+    fn cumtrapz(&self, dx: T) -> [T; N]
+    where
+        T: Float
+    {
+        let two = T::one() + T::one();
+
+        let mut y = [T::zero(); N];
+        let mut i = 1;
+        while i < N
+        {
+            y[i] = y[i - 1] + (self[i - 1] + self[i])*dx/two;
+            i += 1;
+        }
+        y
+    }
+    fn cumtrapz_x(&self, x: &[T; N]) -> [T; N]
+    where
+        T: Float
+    {
+        let two = T::one() + T::one();
+
+        let mut y = [T::zero(); N];
+        let mut i = 1;
+        while i < N
+        {
+            y[i] = y[i - 1] + (x[i] - x[i - 1])*(self[i - 1] + self[i])/two;
+            i += 1;
+        }
+        y
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::ops::*;
+
+    #[test]
+    fn it_works()
+    {
+        assert_eq!([1.0, 2.0].trapz(1.0), 1.5);
+        assert_eq!([1.0].trapz(1.0), 0.0);
+
+        // Simpson's rule is exact for quadratics, also on non-uniform grids
+        let x = [0.0, 0.5, 2.0, 2.5, 4.0];
+        let y = x.map(|x: f64| x*x - 2.0*x);
+        assert!((y.simpson_x(&x) - 16.0/3.0).abs() < 1e-12);
+
+        let y = [0.0, 1.0, 8.0, 27.0, 64.0];
+        assert_eq!(y.simpson(1.0), 64.0);
+
+        let y = [3.0, 1.0, 4.0, 1.0, 5.0];
+        let x = [0.0, 1.0, 2.0, 3.0, 4.0];
+        assert_eq!(y.cumtrapz(1.0)[4], y.trapz(1.0));
+        assert_eq!(y.cumtrapz_x(&x), y.cumtrapz(1.0));
+        assert_eq!(y.trapz_x(&x), y.trapz(1.0));
+    }
+}
\ No newline at end of file
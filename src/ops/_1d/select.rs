@@ -0,0 +1,443 @@
+use core::{cmp::Ordering, marker::Destruct};
+
+use array_trait::Array;
+use num_traits::Float;
+use slice_ops::AsSlice;
+
+#[const_trait]
+pub trait ArraySelect<T, const N: usize>: Array + AsSlice<Item = T>
+{
+    /// Reorders the array in-place such that the element at index `k` is where it would be if the array was sorted,
+    /// with all smaller elements before it and all larger elements after it.
+    /// 
+    /// Uses introselect, which runs in linear time on average, and in `O(N log N)` time in the worst case.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `k >= N`.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let mut a = [5, 1, 4, 2, 3];
+    /// 
+    /// assert_eq!(*a.select_nth(1), 2);
+    /// assert!(a[..1].iter().all(|&x| x <= 2));
+    /// assert!(a[2..].iter().all(|&x| x >= 2));
+    /// ```
+    fn select_nth(&mut self, k: usize) -> &mut T
+    where
+        T: Ord;
+    /// Reorders the array in-place with a comparator function, such that the element at index `k` is where it would be if the array was sorted,
+    /// with all smaller elements before it and all larger elements after it.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `k >= N`.
+    fn select_nth_by<F>(&mut self, k: usize, compare: F) -> &mut T
+    where
+        F: FnMut(&T, &T) -> Ordering + ~const Destruct;
+    /// Finds the element at index `k` if the array was sorted, without reordering the array.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `k >= N`.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [5, 1, 4, 2, 3];
+    /// 
+    /// assert_eq!(a.select_nth_copy(3), 4);
+    /// assert_eq!(a, [5, 1, 4, 2, 3]);
+    /// ```
+    fn select_nth_copy(&self, k: usize) -> T
+    where
+        T: Ord + Copy;
+
+    /// Finds the median, reordering the array in-place. For even lengths, this is the mean of the two middle elements.
+    /// 
+    /// Returns `None` if the array is empty. NaN is ordered after all other values.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// assert_eq!([3.0, 1.0, 2.0].median(), Some(2.0));
+    /// assert_eq!([4.0, 1.0, 3.0, 2.0].median(), Some(2.5));
+    /// ```
+    fn median(&mut self) -> Option<T>
+    where
+        T: Float;
+    /// Finds the median, without reordering the array. For even lengths, this is the mean of the two middle elements.
+    /// 
+    /// Returns `None` if the array is empty.
+    fn median_copy(&self) -> Option<T>
+    where
+        T: Float;
+
+    /// Finds the `q`-quantile, reordering the array in-place. Linearly interpolates between the closest elements.
+    /// 
+    /// Returns `None` if the array is empty, or if `q` is not in the range `[0, 1]`. NaN is ordered after all other values.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let mut a = [10.0, 40.0, 20.0, 30.0, 50.0];
+    /// 
+    /// assert_eq!(a.quantile(0.0), Some(10.0));
+    /// assert_eq!(a.quantile(0.5), Some(30.0));
+    /// assert_eq!(a.quantile(0.625), Some(35.0));
+    /// assert_eq!(a.quantile(1.0), Some(50.0));
+    /// assert_eq!(a.quantile(1.5), None);
+    /// ```
+    fn quantile(&mut self, q: T) -> Option<T>
+    where
+        T: Float;
+    /// Finds the `q`-quantile, without reordering the array. Linearly interpolates between the closest elements.
+    /// 
+    /// Returns `None` if the array is empty, or if `q` is not in the range `[0, 1]`.
+    fn quantile_copy(&self, q: T) -> Option<T>
+    where
+        T: Float;
+
+    /// Computes the percentile rank of a value, i.e. the percentage of elements less than it, counting equal elements as half.
+    /// 
+    /// Returns `None` if the array is empty.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [1.0, 2.0, 3.0, 4.0];
+    /// 
+    /// assert_eq!(a.percentile_rank(0.0), Some(0.0));
+    /// assert_eq!(a.percentile_rank(2.5), Some(50.0));
+    /// assert_eq!(a.percentile_rank(3.0), Some(62.5));
+    /// assert_eq!(a.percentile_rank(5.0), Some(100.0));
+    /// ```
+    fn percentile_rank(&self, x: T) -> Option<T>
+    where
+        T: Float;
+}
+
+impl<T, const N: usize> ArraySelect<T, N> for [T; N]
+{
+    fn select_nth(&mut self, k: usize) -> &mut T
+    where
+        T: Ord
+    {
+        self.select_nth_by(k, Ord::cmp)
+    }
+    fn select_nth_by<F>(&mut self, k: usize, compare: F) -> &mut T
+    where
+        F: FnMut(&T, &T) -> Ordering
+    {
+        assert!(k < N, "index out of bounds");
+        r#impl::introselect_by(self, k, compare);
+        &mut self[k]
+    }
+    fn select_nth_copy(&self, k: usize) -> T
+    where
+        T: Ord + Copy
+    {
+        let mut a = *self;
+        *a.select_nth(k)
+    }
+
+    fn median(&mut self) -> Option<T>
+    where
+        T: Float
+    {
+        if N == 0
+        {
+            return None
+        }
+        let hi = *self.select_nth_by(N/2, r#impl::float_cmp);
+        if N % 2 == 1
+        {
+            return Some(hi)
+        }
+        // After selection, the lower middle element is the largest one to the left
+        let lo = r#impl::max_by(&self[..N/2], r#impl::float_cmp);
+        Some(lo + (hi - lo)/(T::one() + T::one()))
+    }
+    fn median_copy(&self) -> Option<T>
+    where
+        T: Float
+    {
+        let mut a = *self;
+        a.median()
+    }
+
+    fn quantile(&mut self, q: T) -> Option<T>
+    where
+        T: Float
+    {
+        if N == 0 || !(T::zero()..=T::one()).contains(&q)
+        {
+            return None
+        }
+        let h = T::from(N - 1).unwrap()*q;
+        let i = h.floor();
+        let t = h - i;
+        let i = i.to_usize().unwrap();
+
+        let lo = *self.select_nth_by(i, r#impl::float_cmp);
+        if t.is_zero()
+        {
+            return Some(lo)
+        }
+        // After selection, the next element is the smallest one to the right
+        let hi = r#impl::min_by(&self[i + 1..], r#impl::float_cmp);
+        Some(lo + (hi - lo)*t)
+    }
+    fn quantile_copy(&self, q: T) -> Option<T>
+    where
+        T: Float
+    {
+        let mut a = *self;
+        a.quantile(q)
+    }
+
+    fn percentile_rank(&self, x: T) -> Option<T>
+    where
+        T: Float
+    {
+        if N == 0
+        {
+            return None
+        }
+        let mut less = T::zero();
+        let mut equal = T::zero();
+        let mut i = 0;
+        while i < N
+        {
+            if self[i] < x
+            {
+                less = less + T::one();
+            }
+            else if self[i] == x
+            {
+                equal = equal + T::one();
+            }
+            i += 1;
+        }
+        let half = (T::one() + T::one()).recip();
+        Some((less + equal*half)/T::from(N).unwrap()*T::from(100).unwrap())
+    }
+}
+
+mod r#impl
+{
+    use core::cmp::Ordering;
+
+    use num_traits::Float;
+
+    /// Orders floats, with NaN considered larger than anything else.
+    pub(super) fn float_cmp<T>(a: &T, b: &T) -> Ordering
+    where
+        T: Float
+    {
+        a.partial_cmp(b)
+            .unwrap_or_else(|| a.is_nan().cmp(&b.is_nan()))
+    }
+
+    pub(super) fn max_by<T, F>(a: &[T], mut compare: F) -> T
+    where
+        T: Float,
+        F: FnMut(&T, &T) -> Ordering
+    {
+        let mut y = a[0];
+        let mut i = 1;
+        while i < a.len()
+        {
+            if compare(&a[i], &y).is_gt()
+            {
+                y = a[i];
+            }
+            i += 1;
+        }
+        y
+    }
+
+    pub(super) fn min_by<T, F>(a: &[T], mut compare: F) -> T
+    where
+        T: Float,
+        F: FnMut(&T, &T) -> Ordering
+    {
+        max_by(a, |a, b| compare(b, a))
+    }
+
+    /// Quickselect with median-of-three pivots, falling back to heapsort if partitioning keeps being unbalanced.
+    pub(super) fn introselect_by<T, F>(a: &mut [T], k: usize, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering
+    {
+        let mut lo = 0;
+        let mut hi = a.len();
+        let mut budget = 2*(usize::BITS - a.len().leading_zeros());
+        loop
+        {
+            let len = hi - lo;
+            if len <= 8
+            {
+                insertion_sort_by(&mut a[lo..hi], &mut compare);
+                return
+            }
+            if budget == 0
+            {
+                heapsort_by(&mut a[lo..hi], &mut compare);
+                return
+            }
+            budget -= 1;
+
+            let p = lo + partition_by(&mut a[lo..hi], &mut compare);
+            match k.cmp(&p)
+            {
+                Ordering::Less => hi = p,
+                Ordering::Equal => return,
+                Ordering::Greater => lo = p + 1
+            }
+        }
+    }
+
+    /// Partitions around a median-of-three pivot. Returns the final position of the pivot.
+    fn partition_by<T, F>(a: &mut [T], compare: &mut F) -> usize
+    where
+        F: FnMut(&T, &T) -> Ordering
+    {
+        let last = a.len() - 1;
+        let mid = a.len()/2;
+        if compare(&a[mid], &a[0]).is_lt()
+        {
+            a.swap(mid, 0);
+        }
+        if compare(&a[last], &a[0]).is_lt()
+        {
+            a.swap(last, 0);
+        }
+        if compare(&a[mid], &a[last]).is_lt()
+        {
+            a.swap(mid, last);
+        }
+
+        let mut p = 0;
+        let mut i = 0;
+        while i < last
+        {
+            if compare(&a[i], &a[last]).is_lt()
+            {
+                a.swap(i, p);
+                p += 1;
+            }
+            i += 1;
+        }
+        a.swap(p, last);
+        p
+    }
+
+    fn insertion_sort_by<T, F>(a: &mut [T], compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering
+    {
+        let mut i = 1;
+        while i < a.len()
+        {
+            let mut j = i;
+            while j > 0 && compare(&a[j], &a[j - 1]).is_lt()
+            {
+                a.swap(j, j - 1);
+                j -= 1;
+            }
+            i += 1;
+        }
+    }
+
+    fn heapsort_by<T, F>(a: &mut [T], compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering
+    {
+        let mut i = a.len()/2;
+        while i > 0
+        {
+            i -= 1;
+            sift_down_by(a, i, a.len(), compare);
+        }
+        let mut end = a.len();
+        while end > 1
+        {
+            end -= 1;
+            a.swap(0, end);
+            sift_down_by(a, 0, end, compare);
+        }
+    }
+
+    fn sift_down_by<T, F>(a: &mut [T], mut i: usize, end: usize, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering
+    {
+        loop
+        {
+            let mut child = 2*i + 1;
+            if child >= end
+            {
+                return
+            }
+            if child + 1 < end && compare(&a[child], &a[child + 1]).is_lt()
+            {
+                child += 1;
+            }
+            if !compare(&a[i], &a[child]).is_lt()
+            {
+                return
+            }
+            a.swap(i, child);
+            i = child;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::ops::*;
+
+    #[test]
+    fn it_works()
+    {
+        // Long enough to partition a few times before falling back to insertion sort
+        let inputs: [[i32; 20]; 5] = [
+            core::array::from_fn(|i| i as i32),
+            core::array::from_fn(|i| 19 - i as i32),
+            [2; 20],
+            core::array::from_fn(|i| (i as i32).min(19 - i as i32)),
+            [-4, 9, 0, -4, 12, 5, 9, -1, 2, 7, 7, 3, -8, 0, 15, 1, 9, -4, 6, 2]
+        ];
+        for a in inputs
+        {
+            let sorted = a.sorted();
+
+            for k in 0..a.len()
+            {
+                let mut b = a;
+                assert_eq!(*b.select_nth(k), sorted[k]);
+                assert!(b[..k].iter().all(|&x| x <= sorted[k]));
+                assert!(b[k + 1..].iter().all(|&x| x >= sorted[k]));
+            }
+        }
+
+        // Adversarial for naive pivots
+        let a: [i32; 64] = core::array::from_fn(|i| i as i32 % 2);
+        assert_eq!(a.select_nth_copy(31), 0);
+        assert_eq!(a.select_nth_copy(32), 1);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,233 @@
+use array_trait::Array;
+use num_traits::Float;
+use slice_ops::AsSlice;
+
+use crate::Rng;
+
+#[const_trait]
+pub trait ArrayShuffle<T, const N: usize>: Array + AsSlice<Item = T>
+{
+    /// Shuffles the array in-place, so that every permutation is equally likely.
+    /// 
+    /// Uses the Fisher-Yates algorithm, with `N - 1` swaps.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// # let mut state = 0x2545f4914f6cdd1du64;
+    /// # let mut rng = || {
+    /// #     state ^= state << 13;
+    /// #     state ^= state >> 7;
+    /// #     state ^= state << 17;
+    /// #     state
+    /// # };
+    /// # 
+    /// let mut a = [1, 2, 3, 4, 5, 6, 7, 8];
+    /// a.shuffle(&mut rng);
+    /// 
+    /// assert_eq!(a.sorted(), [1, 2, 3, 4, 5, 6, 7, 8]);
+    /// ```
+    fn shuffle<R>(&mut self, rng: &mut R)
+    where
+        R: Rng + ?Sized;
+    /// Returns a shuffled array, so that every permutation is equally likely.
+    fn shuffled<R>(self, rng: &mut R) -> [T; N]
+    where
+        R: Rng + ?Sized;
+
+    /// Picks `K` distinct elements at random, without replacement.
+    /// 
+    /// Every selection, and every order of it, is equally likely.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// # let mut state = 0x2545f4914f6cdd1du64;
+    /// # let mut rng = || {
+    /// #     state ^= state << 13;
+    /// #     state ^= state >> 7;
+    /// #     state ^= state << 17;
+    /// #     state
+    /// # };
+    /// # 
+    /// let a = ['a', 'b', 'c', 'd', 'e'];
+    /// let [x, y, z] = a.sample::<3, _>(&mut rng);
+    /// 
+    /// assert!(x != y && y != z && x != z);
+    /// assert!(a.contains(x) && a.contains(y) && a.contains(z));
+    /// ```
+    fn sample<const K: usize, R>(&self, rng: &mut R) -> [&T; K]
+    where
+        R: Rng + ?Sized,
+        [(); N - K]:;
+
+    /// Picks an index at random, where the probability of each index is proportional to its weight.
+    /// 
+    /// Returns `None` if any weight is negative or NaN, or if the weights don't have a positive and finite sum.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// # let mut state = 0x2545f4914f6cdd1du64;
+    /// # let mut rng = || {
+    /// #     state ^= state << 13;
+    /// #     state ^= state >> 7;
+    /// #     state ^= state << 17;
+    /// #     state
+    /// # };
+    /// # 
+    /// let w = [0.0, 1.0, 0.0, 3.0];
+    /// 
+    /// for _ in 0..100
+    /// {
+    ///     let i = w.weighted_index(&mut rng).unwrap();
+    ///     assert!(i == 1 || i == 3);
+    /// }
+    /// 
+    /// assert_eq!([0.0, 0.0].weighted_index(&mut rng), None);
+    /// assert_eq!([1.0, -1.0].weighted_index(&mut rng), None);
+    /// ```
+    fn weighted_index<R>(&self, rng: &mut R) -> Option<usize>
+    where
+        T: Float,
+        R: Rng + ?Sized;
+}
+
+impl<T, const N: usize> ArrayShuffle<T, N> for [T; N]
+{
+    fn shuffle<R>(&mut self, rng: &mut R)
+    where
+        R: Rng + ?Sized
+    {
+        let mut i = N;
+        while i > 1
+        {
+            let j = rng.next_index(i);
+            i -= 1;
+            self.swap(i, j);
+        }
+    }
+    fn shuffled<R>(mut self, rng: &mut R) -> [T; N]
+    where
+        R: Rng + ?Sized
+    {
+        self.shuffle(rng);
+        self
+    }
+
+    fn sample<const K: usize, R>(&self, rng: &mut R) -> [&T; K]
+    where
+        R: Rng + ?Sized,
+        [(); N - K]:
+    {
+        // Partial Fisher-Yates on the indices, stopping after the first K
+        let mut indices: [usize; N] = crate::from_fn(|i| i);
+        let mut i = 0;
+        while i < K
+        {
+            let j = i + rng.next_index(N - i);
+            indices.swap(i, j);
+            i += 1;
+        }
+        crate::from_fn(|i| &self[indices[i]])
+    }
+
+    fn weighted_index<R>(&self, rng: &mut R) -> Option<usize>
+    where
+        T: Float,
+        R: Rng + ?Sized
+    {
+        let mut total = T::zero();
+        let mut i = 0;
+        while i < N
+        {
+            let w = self[i];
+            if w.is_nan() || w < T::zero()
+            {
+                return None
+            }
+            total = total + w;
+            i += 1;
+        }
+        if !(total.is_finite() && total > T::zero())
+        {
+            return None
+        }
+
+        // A uniform value in [0, total), from 53 random bits
+        let u = T::from(rng.next_u64() >> 11)? * T::from(1.0/(1u64 << 53) as f64)?;
+        let target = u*total;
+
+        let mut sum = T::zero();
+        let mut last = 0;
+        let mut i = 0;
+        while i < N
+        {
+            let w = self[i];
+            if w > T::zero()
+            {
+                sum = sum + w;
+                if target < sum
+                {
+                    return Some(i)
+                }
+                last = i;
+            }
+            i += 1;
+        }
+        // Rounding may leave the target just above the sum
+        Some(last)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::ops::*;
+
+    #[test]
+    fn it_works()
+    {
+        let mut state = 1u64;
+        let mut rng = || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            state
+        };
+
+        let mut counts = [0usize; 6];
+        for _ in 0..6000
+        {
+            let mut a = [0, 1, 2];
+            a.shuffle(&mut rng);
+            let p = match a
+            {
+                [0, 1, 2] => 0,
+                [0, 2, 1] => 1,
+                [1, 0, 2] => 2,
+                [1, 2, 0] => 3,
+                [2, 0, 1] => 4,
+                [2, 1, 0] => 5,
+                _ => unreachable!()
+            };
+            counts[p] += 1;
+        }
+        assert!(counts.into_iter().all(|c| (800..1200).contains(&c)));
+
+        let w = [1.0, 0.0, 3.0];
+        let mut counts = [0usize; 3];
+        for _ in 0..4000
+        {
+            counts[w.weighted_index(&mut rng).unwrap()] += 1;
+        }
+        assert_eq!(counts[1], 0);
+        assert!((800..1200).contains(&counts[0]));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,238 @@
+use core::{cmp::Ordering, marker::Destruct};
+
+use array_trait::Array;
+use slice_ops::AsSlice;
+
+#[const_trait]
+pub trait ArraySort<T, const N: usize>: Array + AsSlice<Item = T>
+{
+    /// Returns the array sorted in ascending order.
+    /// 
+    /// The sort is stable, and performs `O(N log N)` comparisons.
+    /// 
+    /// If `T`'s ordering panics, every element is still dropped exactly once.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [5, 2, 4, 1, 3];
+    /// 
+    /// assert_eq!(a.sorted(), [1, 2, 3, 4, 5]);
+    /// ```
+    fn sorted(self) -> [T; N]
+    where
+        T: Ord;
+    /// Returns the array sorted with a comparator function.
+    /// 
+    /// The sort is stable, and performs `O(N log N)` comparisons.
+    /// 
+    /// If the comparator panics, every element is still dropped exactly once.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [5, 2, 4, 1, 3];
+    /// 
+    /// assert_eq!(a.sorted_by(|a, b| b.cmp(a)), [5, 4, 3, 2, 1]);
+    /// ```
+    fn sorted_by<F>(self, compare: F) -> [T; N]
+    where
+        F: FnMut(&T, &T) -> Ordering + ~const Destruct;
+    /// Returns the array sorted with a key extraction function.
+    /// 
+    /// The sort is stable, and performs `O(N log N)` comparisons.
+    /// 
+    /// If the key function panics, every element is still dropped exactly once.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = ["kiwi", "fig", "banana", "pear"];
+    /// 
+    /// assert_eq!(a.sorted_by_key(|s| s.len()), ["fig", "kiwi", "pear", "banana"]);
+    /// ```
+    fn sorted_by_key<K, F>(self, key: F) -> [T; N]
+    where
+        F: FnMut(&T) -> K + ~const Destruct,
+        K: Ord;
+
+    /// Returns the indices that would sort the array in ascending order.
+    /// 
+    /// The sort is stable, so equal elements keep their relative order.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [30, 10, 20, 10];
+    /// 
+    /// assert_eq!(a.argsort(), [1, 3, 2, 0]);
+    /// ```
+    fn argsort(&self) -> [usize; N]
+    where
+        T: Ord;
+    /// Returns the indices that would sort the array with a comparator function.
+    /// 
+    /// The sort is stable, so equal elements keep their relative order.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [0.5, -1.0, 2.0];
+    /// 
+    /// assert_eq!(a.argsort_by(f64::total_cmp), [1, 0, 2]);
+    /// ```
+    fn argsort_by<F>(&self, compare: F) -> [usize; N]
+    where
+        F: FnMut(&T, &T) -> Ordering + ~const Destruct;
+    /// Returns the indices that would sort the array with a key extraction function.
+    /// 
+    /// The sort is stable, so equal elements keep their relative order.
+    fn argsort_by_key<K, F>(&self, key: F) -> [usize; N]
+    where
+        F: FnMut(&T) -> K + ~const Destruct,
+        K: Ord;
+}
+
+impl<T, const N: usize> ArraySort<T, N> for [T; N]
+{
+    fn sorted(self) -> [T; N]
+    where
+        T: Ord
+    {
+        self.sorted_by(Ord::cmp)
+    }
+    fn sorted_by<F>(mut self, compare: F) -> [T; N]
+    where
+        F: FnMut(&T, &T) -> Ordering
+    {
+        r#impl::binary_insertion_sort_by(&mut self, compare);
+        self
+    }
+    fn sorted_by_key<K, F>(self, mut key: F) -> [T; N]
+    where
+        F: FnMut(&T) -> K,
+        K: Ord
+    {
+        self.sorted_by(|a, b| key(a).cmp(&key(b)))
+    }
+
+    fn argsort(&self) -> [usize; N]
+    where
+        T: Ord
+    {
+        self.argsort_by(Ord::cmp)
+    }
+    fn argsort_by<F>(&self, mut compare: F) -> [usize; N]
+    where
+        F: FnMut(&T, &T) -> Ordering
+    {
+        let mut i = crate::from_fn(|i| i);
+        r#impl::binary_insertion_sort_by(&mut i, |&a, &b| compare(&self[a], &self[b]));
+        i
+    }
+    fn argsort_by_key<K, F>(&self, mut key: F) -> [usize; N]
+    where
+        F: FnMut(&T) -> K,
+        K: Ord
+    {
+        self.argsort_by(|a, b| key(a).cmp(&key(b)))
+    }
+}
+
+mod r#impl
+{
+    use core::cmp::Ordering;
+
+    /// Stable binary insertion sort. Each element is rotated into place, so the slice is always a permutation of the original, even mid-panic.
+    pub(super) fn binary_insertion_sort_by<T, F>(a: &mut [T], mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering
+    {
+        let mut i = 1;
+        while i < a.len()
+        {
+            // Insert after any equal elements, to keep the sort stable
+            let mut lo = 0;
+            let mut hi = i;
+            while lo < hi
+            {
+                let mid = lo + (hi - lo)/2;
+                if compare(&a[i], &a[mid]).is_lt()
+                {
+                    hi = mid;
+                }
+                else
+                {
+                    lo = mid + 1;
+                }
+            }
+            a[lo..=i].rotate_right(1);
+            i += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use std::{panic, rc::Rc, vec::Vec};
+
+    use crate::ops::*;
+
+    #[test]
+    fn it_works()
+    {
+        let inputs: [[i32; 9]; 5] = [
+            [0, 1, 2, 3, 4, 5, 6, 7, 8],
+            [8, 7, 6, 5, 4, 3, 2, 1, 0],
+            [2, 2, 2, 2, 2, 2, 2, 2, 2],
+            [3, 3, 1, 3, 1, 1, 3, 1, 3],
+            [-4, 9, 0, -4, 12, 5, 9, -1, 2]
+        ];
+        for a in inputs
+        {
+            let mut b = a;
+            b.sort();
+
+            assert_eq!(a.sorted(), b);
+            assert_eq!(a.argsort().map(|i| a[i]), b);
+        }
+
+        // Stability
+        let a = [(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd')];
+        assert_eq!(a.sorted_by_key(|x| x.0), [(0, 'b'), (0, 'd'), (1, 'a'), (1, 'c')]);
+        assert_eq!(a.argsort_by_key(|x| x.0), [1, 3, 0, 2]);
+    }
+
+    #[test]
+    fn panic_safety()
+    {
+        let a: [Rc<i32>; 8] = core::array::from_fn(|i| Rc::new((i as i32*5) % 8));
+        let observers: Vec<_> = a.iter().map(Rc::downgrade).collect();
+
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            let mut n = 0;
+            a.sorted_by(|a, b| {
+                n += 1;
+                if n > 10
+                {
+                    panic!()
+                }
+                a.cmp(b)
+            })
+        }));
+
+        assert!(result.is_err());
+        assert!(observers.iter().all(|o| o.strong_count() == 0));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,165 @@
+use core::marker::Destruct;
+
+use array_trait::Array;
+use slice_ops::AsSlice;
+
+use crate::{Ascending, Comparator};
+
+#[const_trait]
+pub trait ArraySortNetwork<T, const N: usize>: Array + AsSlice<Item = T>
+{
+    /// Sorts the array in-place using a sorting network (Batcher's merge-exchange).
+    /// 
+    /// The sequence of compare-exchanges depends only on `N`, and not on the data, so this is branch-light and well suited for small arrays.
+    /// The sort is not stable.
+    /// 
+    /// This can be called in const context for the primitive integers, `bool` and `char`.
+    /// 
+    /// If `T`'s ordering panics, the array is left as some permutation of its original elements.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let mut a = [5, 2, 4, 1, 3, 0];
+    /// 
+    /// a.sort_network();
+    /// 
+    /// assert_eq!(a, [0, 1, 2, 3, 4, 5]);
+    /// ```
+    fn sort_network(&mut self)
+    where
+        T: Ord,
+        Ascending: ~const Comparator<T>;
+    /// Sorts the array in-place using a sorting network (Batcher's merge-exchange), with a comparator.
+    /// 
+    /// The sequence of compare-exchanges depends only on `N`, and not on the data, so this is branch-light and well suited for small arrays.
+    /// The sort is not stable.
+    /// 
+    /// This can be called in const context if the comparator has a const [`Comparator`] implementation.
+    /// 
+    /// If the comparator panics, the array is left as some permutation of its original elements.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let mut a = [5, 2, 4, 1, 3, 0];
+    /// 
+    /// a.sort_network_by(|a: &i32, b: &i32| b.cmp(a));
+    /// 
+    /// assert_eq!(a, [5, 4, 3, 2, 1, 0]);
+    /// ```
+    fn sort_network_by<F>(&mut self, compare: F)
+    where
+        F: ~const Comparator<T> + ~const Destruct;
+    /// Returns the array sorted using a sorting network (Batcher's merge-exchange).
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// assert_eq!([3, 1, 2].sorted_network(), [1, 2, 3]);
+    /// ```
+    fn sorted_network(self) -> [T; N]
+    where
+        T: Ord,
+        Ascending: ~const Comparator<T>;
+}
+
+impl<T, const N: usize> const ArraySortNetwork<T, N> for [T; N]
+{
+    fn sort_network(&mut self)
+    where
+        T: Ord,
+        Ascending: ~const Comparator<T>
+    {
+        self.sort_network_by(Ascending)
+    }
+    fn sort_network_by<F>(&mut self, mut compare: F)
+    where
+        F: ~const Comparator<T> + ~const Destruct
+    {
+        if N < 2
+        {
+            return
+        }
+
+        // Knuth's algorithm 5.2.2M
+        let t = usize::BITS - (N - 1).leading_zeros();
+        let mut p = 1 << (t - 1);
+        while p > 0
+        {
+            let mut q = 1 << (t - 1);
+            let mut r = 0;
+            let mut d = p;
+            while d > 0
+            {
+                let mut i = 0;
+                while i + d < N
+                {
+                    if i & p == r && compare.compare(&self[i], &self[i + d]).is_gt()
+                    {
+                        self.swap(i, i + d);
+                    }
+                    i += 1;
+                }
+                d = q - p;
+                q >>= 1;
+                r = p;
+            }
+            p >>= 1;
+        }
+    }
+    fn sorted_network(mut self) -> [T; N]
+    where
+        T: Ord,
+        Ascending: ~const Comparator<T>
+    {
+        self.sort_network();
+        self
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::ops::*;
+
+    #[test]
+    fn it_works()
+    {
+        const A: [u8; 7] = [5, 2, 6, 2, 0, 7, 1].sorted_network();
+        const B: [char; 4] = {
+            let mut b = ['d', 'a', 'c', 'b'];
+            b.sort_network();
+            b
+        };
+
+        assert_eq!(A, [0, 1, 2, 2, 5, 6, 7]);
+        assert_eq!(B, ['a', 'b', 'c', 'd']);
+
+        let inputs: [[i32; 9]; 4] = [
+            [0, 1, 2, 3, 4, 5, 6, 7, 8],
+            [8, 7, 6, 5, 4, 3, 2, 1, 0],
+            [3, 3, 1, 3, 1, 1, 3, 1, 3],
+            [-4, 9, 0, -4, 12, 5, 9, -1, 2]
+        ];
+        for a in inputs
+        {
+            let mut b = a;
+            b.sort();
+
+            assert_eq!(a.sorted_network(), b);
+
+            let mut c = a;
+            c.sort_network_by(|a: &i32, b: &i32| b.cmp(a));
+            b.reverse();
+
+            assert_eq!(c, b);
+        }
+    }
+}
\ No newline at end of file
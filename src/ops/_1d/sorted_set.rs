@@ -0,0 +1,225 @@
+use array_trait::Array;
+use slice_ops::AsSlice;
+
+#[const_trait]
+pub trait ArraySortedSet<T, const N: usize>: Array + AsSlice<Item = T>
+{
+    /// Computes the union of two sorted arrays.
+    /// 
+    /// Returns a sorted array, where only the given number of leading elements are valid. The rest are filled with default values.
+    /// 
+    /// Elements present in both arrays are only included once, but duplicates within one array are kept.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [1, 3, 5, 7];
+    /// let b = [3, 4, 5];
+    /// 
+    /// let (union, len) = a.union(&b);
+    /// 
+    /// assert_eq!(&union[..len], [1, 3, 4, 5, 7]);
+    /// ```
+    fn union<const M: usize>(&self, rhs: &[T; M]) -> ([T; N + M], usize)
+    where
+        T: Ord + Copy + Default;
+    /// Computes the intersection of two sorted arrays.
+    /// 
+    /// Returns a sorted array, where only the given number of leading elements are valid. The rest are filled with default values.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [1, 3, 5, 7];
+    /// let b = [3, 4, 5];
+    /// 
+    /// let (intersection, len) = a.intersection(&b);
+    /// 
+    /// assert_eq!(&intersection[..len], [3, 5]);
+    /// ```
+    fn intersection<const M: usize>(&self, rhs: &[T; M]) -> ([T; crate::min_len(N, M)], usize)
+    where
+        T: Ord + Copy + Default;
+    /// Computes the difference of two sorted arrays, i.e. the elements in `self` that are not in `rhs`.
+    /// 
+    /// Returns a sorted array, where only the given number of leading elements are valid. The rest are filled with default values.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [1, 3, 5, 7];
+    /// let b = [3, 4, 5];
+    /// 
+    /// let (difference, len) = a.difference(&b);
+    /// 
+    /// assert_eq!(&difference[..len], [1, 7]);
+    /// ```
+    fn difference<const M: usize>(&self, rhs: &[T; M]) -> ([T; N], usize)
+    where
+        T: Ord + Copy + Default;
+    /// Computes the symmetric difference of two sorted arrays, i.e. the elements that are in either array, but not both.
+    /// 
+    /// Returns a sorted array, where only the given number of leading elements are valid. The rest are filled with default values.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [1, 3, 5, 7];
+    /// let b = [3, 4, 5];
+    /// 
+    /// let (symmetric_difference, len) = a.symmetric_difference(&b);
+    /// 
+    /// assert_eq!(&symmetric_difference[..len], [1, 4, 7]);
+    /// ```
+    fn symmetric_difference<const M: usize>(&self, rhs: &[T; M]) -> ([T; N + M], usize)
+    where
+        T: Ord + Copy + Default;
+}
+
+impl<T, const N: usize> ArraySortedSet<T, N> for [T; N]
+{
+    fn union<const M: usize>(&self, rhs: &[T; M]) -> ([T; N + M], usize)
+    where
+        T: Ord + Copy + Default
+    {
+        r#impl::merge(self, rhs, [true, true, true])
+    }
+    fn intersection<const M: usize>(&self, rhs: &[T; M]) -> ([T; crate::min_len(N, M)], usize)
+    where
+        T: Ord + Copy + Default
+    {
+        r#impl::merge(self, rhs, [false, true, false])
+    }
+    fn difference<const M: usize>(&self, rhs: &[T; M]) -> ([T; N], usize)
+    where
+        T: Ord + Copy + Default
+    {
+        r#impl::merge(self, rhs, [true, false, false])
+    }
+    fn symmetric_difference<const M: usize>(&self, rhs: &[T; M]) -> ([T; N + M], usize)
+    where
+        T: Ord + Copy + Default
+    {
+        r#impl::merge(self, rhs, [true, false, true])
+    }
+}
+
+mod r#impl
+{
+    use core::cmp::Ordering;
+
+    /// Walks two sorted arrays in step, keeping elements only in the left, in both, or only in the right, respectively.
+    pub(super) fn merge<T, const N: usize, const M: usize, const C: usize>(lhs: &[T; N], rhs: &[T; M], [keep_left, keep_both, keep_right]: [bool; 3]) -> ([T; C], usize)
+    where
+        T: Ord + Copy + Default
+    {
+        let mut y = [T::default(); C];
+        let mut len = 0;
+        let mut push = |x: T| {
+            y[len] = x;
+            len += 1;
+        };
+
+        let mut i = 0;
+        let mut j = 0;
+        while i < N || j < M
+        {
+            let ordering = if i == N
+            {
+                Ordering::Greater
+            }
+            else if j == M
+            {
+                Ordering::Less
+            }
+            else
+            {
+                lhs[i].cmp(&rhs[j])
+            };
+            match ordering
+            {
+                Ordering::Less => {
+                    if keep_left
+                    {
+                        push(lhs[i])
+                    }
+                    i += 1;
+                },
+                Ordering::Equal => {
+                    if keep_both
+                    {
+                        push(lhs[i])
+                    }
+                    i += 1;
+                    j += 1;
+                },
+                Ordering::Greater => {
+                    if keep_right
+                    {
+                        push(rhs[j])
+                    }
+                    j += 1;
+                }
+            }
+        }
+
+        (y, len)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::ops::*;
+
+    #[test]
+    fn it_works()
+    {
+        // Duplicates are matched pairwise, like a multiset
+        let a = [1, 2, 2, 2, 5];
+        let b = [2, 2, 3];
+
+        let (union, len) = a.union(&b);
+        assert_eq!(&union[..len], [1, 2, 2, 2, 3, 5]);
+        let (intersection, len) = a.intersection(&b);
+        assert_eq!(&intersection[..len], [2, 2]);
+        let (difference, len) = a.difference(&b);
+        assert_eq!(&difference[..len], [1, 2, 5]);
+        let (difference, len) = b.difference(&a);
+        assert_eq!(&difference[..len], [3]);
+        let (symmetric_difference, len) = a.symmetric_difference(&b);
+        assert_eq!(&symmetric_difference[..len], [1, 2, 3, 5]);
+
+        let e: [i32; 0] = [];
+
+        let (union, len) = a.union(&e);
+        assert_eq!(&union[..len], a);
+        let (union, len) = e.union(&b);
+        assert_eq!(&union[..len], b);
+        let (intersection, len) = a.intersection(&e);
+        assert_eq!(len, 0);
+        assert_eq!(intersection, []);
+        let (difference, len) = a.difference(&e);
+        assert_eq!(&difference[..len], a);
+        let (difference, len) = e.difference(&b);
+        assert_eq!(len, 0);
+        assert_eq!(difference, []);
+        let (union, len) = e.union(&e);
+        assert_eq!(len, 0);
+        assert_eq!(union, []);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,370 @@
+use array_trait::Array;
+use num_traits::Float;
+use slice_ops::AsSlice;
+
+#[const_trait]
+pub trait ArrayStatistics<T, const N: usize>: Array + AsSlice<Item = T>
+{
+    /// Computes the arithmetic mean, or `None` if the array is empty.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+    /// 
+    /// assert_eq!(a.mean(), Some(5.0));
+    /// assert_eq!(<[f64; 0]>::default().mean(), None);
+    /// ```
+    fn mean(&self) -> Option<T>
+    where
+        T: Float;
+
+    /// Computes the population variance, or `None` if the array is empty.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+    /// 
+    /// assert_eq!(a.variance(), Some(4.0));
+    /// ```
+    fn variance(&self) -> Option<T>
+    where
+        T: Float;
+    /// Computes the sample variance (with Bessel's correction), or `None` if the array has less than two elements.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+    /// 
+    /// assert_eq!(a.sample_variance(), Some(32.0/7.0));
+    /// assert_eq!([1.0].sample_variance(), None);
+    /// ```
+    fn sample_variance(&self) -> Option<T>
+    where
+        T: Float;
+
+    /// Computes the population standard deviation, or `None` if the array is empty.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+    /// 
+    /// assert_eq!(a.std_dev(), Some(2.0));
+    /// ```
+    fn std_dev(&self) -> Option<T>
+    where
+        T: Float;
+    /// Computes the sample standard deviation (with Bessel's correction), or `None` if the array has less than two elements.
+    fn sample_std_dev(&self) -> Option<T>
+    where
+        T: Float;
+
+    /// Computes the population covariance with another array, or `None` if the arrays are empty.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let x = [1.0, 2.0, 3.0, 4.0];
+    /// let y = [2.0, 4.0, 6.0, 8.0];
+    /// 
+    /// assert_eq!(x.covariance(&y), Some(2.5));
+    /// ```
+    fn covariance(&self, other: &[T; N]) -> Option<T>
+    where
+        T: Float;
+    /// Computes the sample covariance (with Bessel's correction) with another array, or `None` if the arrays have less than two elements.
+    fn sample_covariance(&self, other: &[T; N]) -> Option<T>
+    where
+        T: Float;
+
+    /// Computes the Pearson correlation coefficient with another array, or `None` if the arrays are empty or either has zero variance.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let x = [1.0, 2.0, 3.0, 4.0];
+    /// 
+    /// assert_eq!(x.pearson(&[2.0, 4.0, 6.0, 8.0]), Some(1.0));
+    /// assert_eq!(x.pearson(&[8.0, 6.0, 4.0, 2.0]), Some(-1.0));
+    /// ```
+    fn pearson(&self, other: &[T; N]) -> Option<T>
+    where
+        T: Float;
+
+    /// Computes the population skewness, or `None` if the array is empty or has zero variance.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// assert_eq!([1.0, 2.0, 3.0].skewness(), Some(0.0));
+    /// 
+    /// let right_tailed: f64 = [1.0, 1.0, 1.0, 5.0].skewness().unwrap();
+    /// 
+    /// assert!(right_tailed > 0.0);
+    /// ```
+    fn skewness(&self) -> Option<T>
+    where
+        T: Float;
+    /// Computes the population excess kurtosis, or `None` if the array is empty or has zero variance.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a: [f64; 4] = [-1.0, 1.0, -1.0, 1.0];
+    /// 
+    /// assert_eq!(a.kurtosis(), Some(-2.0));
+    /// ```
+    fn kurtosis(&self) -> Option<T>
+    where
+        T: Float;
+}
+
+impl<T, const N: usize> ArrayStatistics<T, N> for [T; N]
+{
+    fn mean(&self) -> Option<T>
+    where
+        T: Float
+    {
+        if N == 0
+        {
+            return None
+        }
+        let (mean, _) = r#impl::welford(self);
+        Some(mean)
+    }
+
+    fn variance(&self) -> Option<T>
+    where
+        T: Float
+    {
+        if N == 0
+        {
+            return None
+        }
+        let (_, m2) = r#impl::welford(self);
+        Some(m2/T::from(N).unwrap())
+    }
+    fn sample_variance(&self) -> Option<T>
+    where
+        T: Float
+    {
+        if N < 2
+        {
+            return None
+        }
+        let (_, m2) = r#impl::welford(self);
+        Some(m2/T::from(N - 1).unwrap())
+    }
+
+    fn std_dev(&self) -> Option<T>
+    where
+        T: Float
+    {
+        self.variance().map(Float::sqrt)
+    }
+    fn sample_std_dev(&self) -> Option<T>
+    where
+        T: Float
+    {
+        self.sample_variance().map(Float::sqrt)
+    }
+
+    fn covariance(&self, other: &[T; N]) -> Option<T>
+    where
+        T: Float
+    {
+        if N == 0
+        {
+            return None
+        }
+        let [_, _, cxy] = r#impl::co_moments(self, other);
+        Some(cxy/T::from(N).unwrap())
+    }
+    fn sample_covariance(&self, other: &[T; N]) -> Option<T>
+    where
+        T: Float
+    {
+        if N < 2
+        {
+            return None
+        }
+        let [_, _, cxy] = r#impl::co_moments(self, other);
+        Some(cxy/T::from(N - 1).unwrap())
+    }
+
+    fn pearson(&self, other: &[T; N]) -> Option<T>
+    where
+        T: Float
+    {
+        if N == 0
+        {
+            return None
+        }
+        let [m2x, m2y, cxy] = r#impl::co_moments(self, other);
+        if m2x.is_zero() || m2y.is_zero()
+        {
+            return None
+        }
+        Some(cxy/(m2x*m2y).sqrt())
+    }
+
+    fn skewness(&self) -> Option<T>
+    where
+        T: Float
+    {
+        if N == 0
+        {
+            return None
+        }
+        let [m2, m3, _] = r#impl::higher_moments(self);
+        if m2.is_zero()
+        {
+            return None
+        }
+        let n = T::from(N).unwrap();
+        Some(n.sqrt()*m3/(m2*m2.sqrt()))
+    }
+    fn kurtosis(&self) -> Option<T>
+    where
+        T: Float
+    {
+        if N == 0
+        {
+            return None
+        }
+        let [m2, _, m4] = r#impl::higher_moments(self);
+        if m2.is_zero()
+        {
+            return None
+        }
+        let n = T::from(N).unwrap();
+        Some(n*m4/(m2*m2) - T::from(3).unwrap())
+    }
+}
+
+mod r#impl
+{
+    use num_traits::Float;
+
+    /// Welford's algorithm. Returns the mean and the sum of squared deviations.
+    pub(super) fn welford<T, const N: usize>(x: &[T; N]) -> (T, T)
+    where
+        T: Float
+    {
+        let mut mean = T::zero();
+        let mut m2 = T::zero();
+        let mut i = 0;
+        while i < N
+        {
+            let n = T::from(i + 1).unwrap();
+            let delta = x[i] - mean;
+            mean = mean + delta/n;
+            m2 = m2 + delta*(x[i] - mean);
+            i += 1;
+        }
+        (mean, m2)
+    }
+
+    /// Welford's algorithm for two variables. Returns the sums of squared deviations and the sum of co-deviations.
+    pub(super) fn co_moments<T, const N: usize>(x: &[T; N], y: &[T; N]) -> [T; 3]
+    where
+        T: Float
+    {
+        let mut mean_x = T::zero();
+        let mut mean_y = T::zero();
+        let mut m2x = T::zero();
+        let mut m2y = T::zero();
+        let mut cxy = T::zero();
+        let mut i = 0;
+        while i < N
+        {
+            let n = T::from(i + 1).unwrap();
+            let dx = x[i] - mean_x;
+            let dy = y[i] - mean_y;
+            mean_x = mean_x + dx/n;
+            mean_y = mean_y + dy/n;
+            m2x = m2x + dx*(x[i] - mean_x);
+            m2y = m2y + dy*(y[i] - mean_y);
+            cxy = cxy + dx*(y[i] - mean_y);
+            i += 1;
+        }
+        [m2x, m2y, cxy]
+    }
+
+    /// Welford's algorithm, extended to higher moments by Terriberry. Returns the sums of the second, third and fourth powers of the deviations.
+    pub(super) fn higher_moments<T, const N: usize>(x: &[T; N]) -> [T; 3]
+    where
+        T: Float
+    {
+        let two = T::from(2).unwrap();
+        let three = T::from(3).unwrap();
+        let four = T::from(4).unwrap();
+        let six = T::from(6).unwrap();
+
+        let mut mean = T::zero();
+        let mut m2 = T::zero();
+        let mut m3 = T::zero();
+        let mut m4 = T::zero();
+        let mut i = 0;
+        while i < N
+        {
+            let n1 = T::from(i).unwrap();
+            let n = T::from(i + 1).unwrap();
+            let delta = x[i] - mean;
+            let delta_n = delta/n;
+            let delta_n2 = delta_n*delta_n;
+            let term = delta*delta_n*n1;
+            mean = mean + delta_n;
+            m4 = m4 + term*delta_n2*(n*n - three*n + three) + six*delta_n2*m2 - four*delta_n*m3;
+            m3 = m3 + term*delta_n*(n - two) - three*delta_n*m2;
+            m2 = m2 + term;
+            i += 1;
+        }
+        [m2, m3, m4]
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::ops::*;
+
+    #[test]
+    fn it_works()
+    {
+        // Large offset, where the naive sum of squares loses all precision
+        let a: [f64; 4] = [1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0];
+
+        assert_eq!(a.variance(), Some(22.5));
+        assert_eq!(a.sample_variance(), Some(30.0));
+        assert_eq!(a.covariance(&a), a.variance());
+        assert!((a.pearson(&a).unwrap() - 1.0).abs() < 1e-12);
+        assert!(a.skewness().unwrap().abs() < 1e-9);
+
+        let c = [2.0, 2.0, 2.0];
+
+        assert_eq!(c.variance(), Some(0.0));
+        assert_eq!(c.pearson(&a[..3].try_into().unwrap()), None);
+        assert_eq!(c.skewness(), None);
+        assert_eq!(c.kurtosis(), None);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,125 @@
+use core::ops::{Add, Sub};
+
+use array_trait::Array;
+use slice_ops::AsSlice;
+
+use super::ArrayPermute;
+
+#[const_trait]
+pub trait ArrayWalshHadamard<T, const N: usize>: Array + AsSlice<Item = T>
+{
+    /// Performs the fast Walsh-Hadamard transform in-place, with the output in natural (Hadamard) order. Length must be a power of 2.
+    /// 
+    /// The transform is not normalized, so applying it twice scales the array by `N`.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let mut a = [1, 0, 1, 0, 0, 1, 1, 0];
+    /// 
+    /// a.walsh_hadamard();
+    /// 
+    /// assert_eq!(a, [4, 2, 0, -2, 0, 2, 0, 2]);
+    /// 
+    /// a.walsh_hadamard();
+    /// 
+    /// assert_eq!(a, [8, 0, 8, 0, 0, 8, 8, 0]);
+    /// ```
+    fn walsh_hadamard(&mut self)
+    where
+        T: Add<Output = T> + Sub<Output = T> + Copy,
+        [(); N.is_power_of_two() as usize - 1]:;
+
+    /// Performs the fast Walsh-Hadamard transform in-place, with the output in sequency (Walsh) order. Length must be a power of 2.
+    /// 
+    /// The `i`'th output corresponds to the Walsh function with `i` sign changes.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let mut a = [1, -1, -1, 1];
+    /// 
+    /// a.walsh_hadamard_sequency();
+    /// 
+    /// assert_eq!(a, [0, 0, 4, 0]);
+    /// ```
+    fn walsh_hadamard_sequency(&mut self)
+    where
+        T: Add<Output = T> + Sub<Output = T> + Copy,
+        [(); N.is_power_of_two() as usize - 1]:,
+        [(); slice_ops::is_power_of(N, 2) as usize - 1]:;
+}
+
+impl<T, const N: usize> ArrayWalshHadamard<T, N> for [T; N]
+{
+    fn walsh_hadamard(&mut self)
+    where
+        T: Add<Output = T> + Sub<Output = T> + Copy,
+        [(); N.is_power_of_two() as usize - 1]:
+    {
+        let mut h = 1;
+        while h < N
+        {
+            let mut i = 0;
+            while i < N
+            {
+                let mut j = i;
+                while j < i + h
+                {
+                    let x = self[j];
+                    let y = self[j + h];
+                    self[j] = x + y;
+                    self[j + h] = x - y;
+                    j += 1;
+                }
+                i += h*2;
+            }
+            h *= 2;
+        }
+    }
+
+    fn walsh_hadamard_sequency(&mut self)
+    where
+        T: Add<Output = T> + Sub<Output = T> + Copy,
+        [(); N.is_power_of_two() as usize - 1]:,
+        [(); slice_ops::is_power_of(N, 2) as usize - 1]:
+    {
+        self.walsh_hadamard();
+        self.bit_rev_permutation();
+        self.grey_code_permutation();
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::ops::*;
+
+    #[test]
+    fn it_works()
+    {
+        const N: usize = 16;
+
+        for h in 0..N
+        {
+            let row: [i32; N] = core::array::from_fn(|i| if (h & i).count_ones() % 2 == 0 {1} else {-1});
+            let sequency = row.as_slice().windows(2).filter(|w| w[0] != w[1]).count();
+
+            let mut a = row;
+            a.walsh_hadamard();
+            assert_eq!(a, core::array::from_fn(|i| if i == h {N as i32} else {0}));
+
+            let mut a = row;
+            a.walsh_hadamard_sequency();
+            assert_eq!(a, core::array::from_fn(|i| if i == sequency {N as i32} else {0}));
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,292 @@
+use core::{marker::Destruct, pin::Pin};
+
+use array_trait::Array;
+use slice_ops::AsSlice;
+
+#[const_trait]
+pub trait ArrayWindows<T, const N: usize>: Array + AsSlice<Item = T>
+{
+    /// Copies out every overlapping window of length `W`, from left to right.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [1, 2, 3, 4];
+    /// 
+    /// assert_eq!(a.windows::<2>(), [[1, 2], [2, 3], [3, 4]]);
+    /// ```
+    fn windows<const W: usize>(&self) -> [[T; W]; N - W + 1]
+    where
+        T: Copy;
+    /// Yields a reference to every overlapping window of length `W`, from left to right.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = ["one", "two", "three"];
+    /// 
+    /// assert_eq!(a.windows_ref::<2>(), [&["one", "two"], &["two", "three"]]);
+    /// ```
+    fn windows_ref<const W: usize>(&self) -> [&[T; W]; N - W + 1];
+    fn windows_pin_ref<const W: usize>(self: Pin<&Self>) -> [Pin<&[T; W]>; N - W + 1];
+
+    /// Maps every overlapping window of length `W`, from left to right.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [1.0, 2.0, 6.0, 3.0, 7.0];
+    /// 
+    /// let moving_average = a.map_windows(|[a, b, c]: [f64; 3]| (a + b + c)/3.0);
+    /// 
+    /// assert_eq!(moving_average, [3.0, 11.0/3.0, 16.0/3.0]);
+    /// ```
+    fn map_windows<Map, const W: usize>(&self, mapper: Map) -> [Map::Output; N - W + 1]
+    where
+        Map: FnMut<([T; W],)> + ~const Destruct,
+        T: Copy;
+    fn map_windows_ref<'a, Map, const W: usize>(&'a self, mapper: Map) -> [Map::Output; N - W + 1]
+    where
+        Map: FnMut<(&'a [T; W],)> + ~const Destruct;
+    fn map_windows_pin_ref<'a, Map, const W: usize>(self: Pin<&'a Self>, mapper: Map) -> [Map::Output; N - W + 1]
+    where
+        Map: FnMut<(Pin<&'a [T; W]>,)> + ~const Destruct;
+
+    /// Visits every overlapping window of length `W` once, from left to right.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [1, 2, 4, 8];
+    /// 
+    /// let mut max_step = 0;
+    /// a.visit_windows(|[a, b]: &[i32; 2]| max_step = max_step.max(b - a));
+    /// 
+    /// assert_eq!(max_step, 4);
+    /// ```
+    fn visit_windows<'a, F, const W: usize>(&'a self, visitor: F)
+    where
+        F: FnMut(&'a [T; W]) + ~const Destruct,
+        T: 'a;
+    /// Mutably visits every overlapping window of length `W` once, from left to right.
+    /// 
+    /// Since windows overlap, changes made in one window are seen by the next.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let mut a = [1, 2, 3, 4];
+    /// 
+    /// a.visit_windows_mut(|[a, b]: &mut [i32; 2]| *b += *a);
+    /// 
+    /// assert_eq!(a, [1, 3, 6, 10]);
+    /// ```
+    fn visit_windows_mut<F, const W: usize>(&mut self, visitor: F)
+    where
+        F: FnMut(&mut [T; W]) + ~const Destruct;
+    fn visit_windows_pin<'a, F, const W: usize>(self: Pin<&'a Self>, visitor: F)
+    where
+        F: FnMut(Pin<&'a [T; W]>) + ~const Destruct,
+        T: 'a;
+    fn visit_windows_pin_mut<F, const W: usize>(self: Pin<&mut Self>, visitor: F)
+    where
+        F: FnMut(Pin<&mut [T; W]>) + ~const Destruct;
+
+    /// Visits every overlapping window of length `W` once, from right to left.
+    fn rvisit_windows<'a, F, const W: usize>(&'a self, visitor: F)
+    where
+        F: FnMut(&'a [T; W]) + ~const Destruct,
+        T: 'a;
+    /// Mutably visits every overlapping window of length `W` once, from right to left.
+    /// 
+    /// Since windows overlap, changes made in one window are seen by the next.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let mut a = [1, 2, 3, 4];
+    /// 
+    /// a.rvisit_windows_mut(|[a, b]: &mut [i32; 2]| *a += *b);
+    /// 
+    /// assert_eq!(a, [10, 9, 7, 4]);
+    /// ```
+    fn rvisit_windows_mut<F, const W: usize>(&mut self, visitor: F)
+    where
+        F: FnMut(&mut [T; W]) + ~const Destruct;
+    fn rvisit_windows_pin<'a, F, const W: usize>(self: Pin<&'a Self>, visitor: F)
+    where
+        F: FnMut(Pin<&'a [T; W]>) + ~const Destruct,
+        T: 'a;
+    fn rvisit_windows_pin_mut<F, const W: usize>(self: Pin<&mut Self>, visitor: F)
+    where
+        F: FnMut(Pin<&mut [T; W]>) + ~const Destruct;
+}
+
+impl<T, const N: usize> ArrayWindows<T, N> for [T; N]
+{
+    fn windows<const W: usize>(&self) -> [[T; W]; N - W + 1]
+    where
+        T: Copy
+    {
+        crate::from_fn(|i| *r#impl::window_ref(self, i))
+    }
+    fn windows_ref<const W: usize>(&self) -> [&[T; W]; N - W + 1]
+    {
+        crate::from_fn(|i| r#impl::window_ref(self, i))
+    }
+    fn windows_pin_ref<const W: usize>(self: Pin<&Self>) -> [Pin<&[T; W]>; N - W + 1]
+    {
+        let array = self.get_ref();
+        crate::from_fn(|i| unsafe {
+            Pin::new_unchecked(r#impl::window_ref(array, i))
+        })
+    }
+
+    fn map_windows<Map, const W: usize>(&self, mut mapper: Map) -> [Map::Output; N - W + 1]
+    where
+        Map: FnMut<([T; W],)>,
+        T: Copy
+    {
+        crate::from_fn(|i| mapper(*r#impl::window_ref(self, i)))
+    }
+    fn map_windows_ref<'a, Map, const W: usize>(&'a self, mut mapper: Map) -> [Map::Output; N - W + 1]
+    where
+        Map: FnMut<(&'a [T; W],)>
+    {
+        crate::from_fn(|i| mapper(r#impl::window_ref(self, i)))
+    }
+    fn map_windows_pin_ref<'a, Map, const W: usize>(self: Pin<&'a Self>, mut mapper: Map) -> [Map::Output; N - W + 1]
+    where
+        Map: FnMut<(Pin<&'a [T; W]>,)>
+    {
+        let array = self.get_ref();
+        crate::from_fn(|i| mapper(unsafe {
+            Pin::new_unchecked(r#impl::window_ref(array, i))
+        }))
+    }
+
+    fn visit_windows<'a, F, const W: usize>(&'a self, mut visitor: F)
+    where
+        F: FnMut(&'a [T; W]),
+        T: 'a
+    {
+        let mut i = 0;
+        while i + W <= N
+        {
+            visitor(r#impl::window_ref(self, i));
+            i += 1;
+        }
+    }
+    fn visit_windows_mut<F, const W: usize>(&mut self, mut visitor: F)
+    where
+        F: FnMut(&mut [T; W])
+    {
+        let mut i = 0;
+        while i + W <= N
+        {
+            visitor(r#impl::window_mut(self, i));
+            i += 1;
+        }
+    }
+    fn visit_windows_pin<'a, F, const W: usize>(self: Pin<&'a Self>, mut visitor: F)
+    where
+        F: FnMut(Pin<&'a [T; W]>),
+        T: 'a
+    {
+        self.get_ref().visit_windows(|x| visitor(unsafe {
+            Pin::new_unchecked(x)
+        }))
+    }
+    fn visit_windows_pin_mut<F, const W: usize>(self: Pin<&mut Self>, mut visitor: F)
+    where
+        F: FnMut(Pin<&mut [T; W]>)
+    {
+        unsafe {
+            self.get_unchecked_mut().visit_windows_mut(|x| visitor(Pin::new_unchecked(x)))
+        }
+    }
+
+    fn rvisit_windows<'a, F, const W: usize>(&'a self, mut visitor: F)
+    where
+        F: FnMut(&'a [T; W]),
+        T: 'a
+    {
+        let mut i = (N + 1).saturating_sub(W);
+        while i > 0
+        {
+            i -= 1;
+            visitor(r#impl::window_ref(self, i));
+        }
+    }
+    fn rvisit_windows_mut<F, const W: usize>(&mut self, mut visitor: F)
+    where
+        F: FnMut(&mut [T; W])
+    {
+        let mut i = (N + 1).saturating_sub(W);
+        while i > 0
+        {
+            i -= 1;
+            visitor(r#impl::window_mut(self, i));
+        }
+    }
+    fn rvisit_windows_pin<'a, F, const W: usize>(self: Pin<&'a Self>, mut visitor: F)
+    where
+        F: FnMut(Pin<&'a [T; W]>),
+        T: 'a
+    {
+        self.get_ref().rvisit_windows(|x| visitor(unsafe {
+            Pin::new_unchecked(x)
+        }))
+    }
+    fn rvisit_windows_pin_mut<F, const W: usize>(self: Pin<&mut Self>, mut visitor: F)
+    where
+        F: FnMut(Pin<&mut [T; W]>)
+    {
+        unsafe {
+            self.get_unchecked_mut().rvisit_windows_mut(|x| visitor(Pin::new_unchecked(x)))
+        }
+    }
+}
+
+mod r#impl
+{
+    pub(super) fn window_ref<T, const N: usize, const W: usize>(array: &[T; N], i: usize) -> &[T; W]
+    {
+        assert!(i + W <= N);
+        unsafe {
+            array.as_ptr().add(i).cast::<[T; W]>().as_ref_unchecked()
+        }
+    }
+
+    pub(super) fn window_mut<T, const N: usize, const W: usize>(array: &mut [T; N], i: usize) -> &mut [T; W]
+    {
+        assert!(i + W <= N);
+        unsafe {
+            array.as_mut_ptr().add(i).cast::<[T; W]>().as_mut_unchecked()
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,87 @@
+use array_trait::Array;
+use num_traits::Float;
+use slice_ops::AsSlice;
+
+#[const_trait]
+pub trait ArrayCholeskyDecompose<T, const N: usize>: Array + AsSlice<Item = [T; N]>
+{
+    /// Performs a Cholesky-decomposition of a symmetric positive-definite matrix, such that `A = LLᵀ`.
+    /// 
+    /// Returns the lower triangular matrix `L`, or `None` if the matrix is not positive-definite.
+    /// 
+    /// Only the lower triangle of the matrix is read, and it is assumed to be symmetric.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [
+    ///     [4.0, 2.0],
+    ///     [2.0, 5.0]
+    /// ];
+    /// 
+    /// let l = a.cholesky_decompose().unwrap();
+    /// 
+    /// assert_eq!(l, [
+    ///     [2.0, 0.0],
+    ///     [1.0, 2.0]
+    /// ]);
+    /// assert_eq!(l.mul_matrix(&l.transpose()), a);
+    /// 
+    /// let indefinite = [
+    ///     [1.0, 2.0],
+    ///     [2.0, 1.0]
+    /// ];
+    /// 
+    /// assert_eq!(indefinite.cholesky_decompose(), None);
+    /// ```
+    fn cholesky_decompose(&self) -> Option<[[T; N]; N]>
+    where
+        T: Float;
+}
+
+impl<T, const N: usize> ArrayCholeskyDecompose<T, N> for [[T; N]; N]
+{
+    fn cholesky_decompose(&self) -> Option<[[T; N]; N]>
+    where
+        T: Float
+    {
+        let mut l = [[T::zero(); N]; N];
+
+        let mut j = 0;
+        while j < N
+        {
+            let mut d = self[j][j];
+            let mut k = 0;
+            while k < j
+            {
+                d = d - l[j][k]*l[j][k];
+                k += 1;
+            }
+            if d.is_nan() || d <= T::zero()
+            {
+                return None
+            }
+            let d = d.sqrt();
+            l[j][j] = d;
+
+            let mut i = j + 1;
+            while i < N
+            {
+                let mut x = self[i][j];
+                let mut k = 0;
+                while k < j
+                {
+                    x = x - l[i][k]*l[j][k];
+                    k += 1;
+                }
+                l[i][j] = x/d;
+                i += 1;
+            }
+            j += 1;
+        }
+
+        Some(l)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,73 @@
+use core::cmp::Ordering;
+
+use array_trait::Array;
+use num_traits::Float;
+use slice_ops::AsSlice;
+
+use crate::private::lu;
+
+#[const_trait]
+pub trait ArrayLuDecompose<T, const N: usize>: Array + AsSlice<Item = [T; N]>
+{
+    /// Performs an LU-decomposition with partial pivoting, such that `PA = LU`.
+    /// 
+    /// Returns the row-permutation `P`, the unit lower triangular matrix `L` and the upper triangular matrix `U`.
+    /// The permutation is given as indices, so that row `i` of `LU` is row `P[i]` of `A`.
+    /// 
+    /// Singular matrices can also be decomposed, in which case `U` will have zeros on its diagonal.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [
+    ///     [1.0, 2.0],
+    ///     [4.0, 4.0]
+    /// ];
+    /// 
+    /// let (p, l, u) = a.lu_decompose();
+    /// 
+    /// assert_eq!(p, [1, 0]);
+    /// assert_eq!(l, [
+    ///     [1.0, 0.0],
+    ///     [0.25, 1.0]
+    /// ]);
+    /// assert_eq!(u, [
+    ///     [4.0, 4.0],
+    ///     [0.0, 1.0]
+    /// ]);
+    /// assert_eq!(l.mul_matrix(&u), [a[p[0]], a[p[1]]]);
+    /// ```
+    fn lu_decompose(&self) -> ([usize; N], [[T; N]; N], [[T; N]; N])
+    where
+        T: Float;
+}
+
+impl<T, const N: usize> ArrayLuDecompose<T, N> for [[T; N]; N]
+{
+    fn lu_decompose(&self) -> ([usize; N], [[T; N]; N], [[T; N]; N])
+    where
+        T: Float
+    {
+        let mut lu = *self;
+        let (p, _) = lu::lu_assign(&mut lu);
+
+        let l = crate::from_fn(|i| crate::from_fn(|j| match i.cmp(&j)
+        {
+            Ordering::Greater => lu[i][j],
+            Ordering::Equal => T::one(),
+            Ordering::Less => T::zero()
+        }));
+        let u = crate::from_fn(|i| crate::from_fn(|j| if i <= j
+        {
+            lu[i][j]
+        }
+        else
+        {
+            T::zero()
+        }));
+
+        (p, l, u)
+    }
+}
\ No newline at end of file
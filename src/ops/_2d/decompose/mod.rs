@@ -0,0 +1,7 @@
+moddef::moddef!(
+    flat(pub) mod {
+        cholesky_decompose,
+        lu_decompose,
+        qr_decompose
+    }
+);
\ No newline at end of file
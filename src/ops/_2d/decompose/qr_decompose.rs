@@ -0,0 +1,166 @@
+use array_trait::Array;
+use num_traits::Float;
+use slice_ops::AsSlice;
+
+#[const_trait]
+pub trait ArrayQrDecompose<T, const M: usize, const N: usize>: Array + AsSlice<Item = [T; N]>
+{
+    /// Performs a QR-decomposition using Householder reflections, such that `A = QR`.
+    /// 
+    /// Returns the orthogonal matrix `Q` and the upper triangular matrix `R`.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [
+    ///     [3.0, 1.0],
+    ///     [4.0, 2.0],
+    ///     [0.0, 5.0]
+    /// ];
+    /// 
+    /// let (q, r): ([[f64; 3]; 3], _) = a.qr_decompose();
+    /// 
+    /// assert_eq!(r[1][0], 0.0);
+    /// assert_eq!(r[2], [0.0, 0.0]);
+    /// 
+    /// let qr = q.mul_matrix(&r);
+    /// for (qr, a) in qr.into_iter().flatten().zip(a.into_iter().flatten())
+    /// {
+    ///     assert!((qr - a).abs() < 1e-12);
+    /// }
+    /// 
+    /// let qtq = q.transpose().mul_matrix(&q);
+    /// for (i, row) in qtq.into_iter().enumerate()
+    /// {
+    ///     for (j, x) in row.into_iter().enumerate()
+    ///     {
+    ///         assert!((x - if i == j {1.0} else {0.0}).abs() < 1e-12);
+    ///     }
+    /// }
+    /// ```
+    fn qr_decompose(&self) -> ([[T; M]; M], [[T; N]; M])
+    where
+        T: Float;
+}
+
+impl<T, const M: usize, const N: usize> ArrayQrDecompose<T, M, N> for [[T; N]; M]
+{
+    fn qr_decompose(&self) -> ([[T; M]; M], [[T; N]; M])
+    where
+        T: Float
+    {
+        let mut q: [[T; M]; M] = crate::from_fn(|i| crate::from_fn(|j| if i == j {T::one()} else {T::zero()}));
+        let mut r = *self;
+
+        let mut k = 0;
+        while k < M && k < N
+        {
+            // Householder vector of the k'th collumn, below the diagonal
+            let mut v = [T::zero(); M];
+            let mut norm = T::zero();
+            let mut i = k;
+            while i < M
+            {
+                v[i] = r[i][k];
+                norm = norm.hypot(v[i]);
+                i += 1;
+            }
+            let alpha = if v[k].is_sign_negative() {norm} else {-norm};
+            v[k] = v[k] - alpha;
+
+            let mut vv = T::zero();
+            let mut i = k;
+            while i < M
+            {
+                vv = vv + v[i]*v[i];
+                i += 1;
+            }
+
+            if !vv.is_zero()
+            {
+                let two = T::one() + T::one();
+
+                // R = HR
+                let mut j = k;
+                while j < N
+                {
+                    let mut s = T::zero();
+                    let mut i = k;
+                    while i < M
+                    {
+                        s = s + v[i]*r[i][j];
+                        i += 1;
+                    }
+                    let s = two*s/vv;
+                    let mut i = k;
+                    while i < M
+                    {
+                        r[i][j] = r[i][j] - s*v[i];
+                        i += 1;
+                    }
+                    j += 1;
+                }
+
+                // Q = QH
+                let mut i = 0;
+                while i < M
+                {
+                    let mut s = T::zero();
+                    let mut j = k;
+                    while j < M
+                    {
+                        s = s + q[i][j]*v[j];
+                        j += 1;
+                    }
+                    let s = two*s/vv;
+                    let mut j = k;
+                    while j < M
+                    {
+                        q[i][j] = q[i][j] - s*v[j];
+                        j += 1;
+                    }
+                    i += 1;
+                }
+
+                // Eliminated exactly
+                r[k][k] = alpha;
+                let mut i = k + 1;
+                while i < M
+                {
+                    r[i][k] = T::zero();
+                    i += 1;
+                }
+            }
+            k += 1;
+        }
+
+        (q, r)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::ops::*;
+
+    #[test]
+    fn it_works()
+    {
+        let a = [
+            [1.0, -2.0, 3.0, 0.5],
+            [-4.0, 5.0, 0.0, 2.0]
+        ];
+
+        let (q, r): ([[f64; 2]; 2], _) = a.qr_decompose();
+
+        assert_eq!(r[1][0], 0.0);
+
+        let qr = q.mul_matrix(&r);
+        for (qr, a) in qr.into_iter().flatten().zip(a.into_iter().flatten())
+        {
+            assert!((qr - a).abs() < 1e-12);
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,216 @@
+use array_trait::Array;
+use num_traits::Float;
+use slice_ops::AsSlice;
+
+use crate::private::lu;
+
+use super::ArrayTransposeAssign;
+
+#[const_trait]
+pub trait ArrayInverse<T, const N: usize>: Array + AsSlice<Item = [T; N]>
+{
+    /// Computes the determinant of a square matrix, using LU-decomposition with partial pivoting.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [
+    ///     [2.0, 0.0, 1.0],
+    ///     [1.0, 3.0, 2.0],
+    ///     [1.0, 1.0, 2.0]
+    /// ];
+    /// 
+    /// let det: f64 = a.determinant();
+    /// 
+    /// assert!((det - 6.0).abs() < 1e-12);
+    /// ```
+    fn determinant(&self) -> T
+    where
+        T: Float;
+
+    /// Computes the inverse of a square matrix, using LU-decomposition with partial pivoting.
+    /// 
+    /// Returns `None` if a pivot is exactly zero, or if the inverse isn't finite.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [
+    ///     [2.0, 1.0],
+    ///     [1.0, 1.0]
+    /// ];
+    /// 
+    /// let a_inv = a.try_inverse().unwrap();
+    /// 
+    /// assert_eq!(a_inv, [
+    ///     [1.0, -1.0],
+    ///     [-1.0, 2.0]
+    /// ]);
+    /// 
+    /// let singular = [
+    ///     [1.0, 2.0],
+    ///     [2.0, 4.0]
+    /// ];
+    /// 
+    /// assert_eq!(singular.try_inverse(), None);
+    /// ```
+    fn try_inverse(&self) -> Option<[[T; N]; N]>
+    where
+        T: Float;
+
+    /// Solves the linear system `Ax = b` for `x`, using LU-decomposition with partial pivoting.
+    /// 
+    /// Returns `None` if a pivot is exactly zero, or if the solution isn't finite.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [
+    ///     [2.0, 1.0],
+    ///     [1.0, 3.0]
+    /// ];
+    /// 
+    /// let x: [f64; 2] = a.solve([3.0, 5.0]).unwrap();
+    /// 
+    /// assert!((x[0] - 0.8).abs() < 1e-12);
+    /// assert!((x[1] - 1.4).abs() < 1e-12);
+    /// ```
+    fn solve(&self, rhs: [T; N]) -> Option<[T; N]>
+    where
+        T: Float;
+}
+
+impl<T, const N: usize> ArrayInverse<T, N> for [[T; N]; N]
+{
+    fn determinant(&self) -> T
+    where
+        T: Float
+    {
+        let mut a = *self;
+        let (_, odd) = lu::lu_assign(&mut a);
+
+        let mut det = if odd {-T::one()} else {T::one()};
+        let mut i = 0;
+        while i < N
+        {
+            det = det*a[i][i];
+            i += 1;
+        }
+        det
+    }
+
+    fn try_inverse(&self) -> Option<[[T; N]; N]>
+    where
+        T: Float
+    {
+        let mut a = *self;
+        let (p, _) = lu::lu_assign(&mut a);
+        if lu::triangle_is_singular(&a, true)
+        {
+            return None
+        }
+
+        let mut inv = crate::from_fn(|j| {
+            let e = crate::from_fn(|i| if i == j {T::one()} else {T::zero()});
+            lu::lu_solve(&a, &p, &e)
+        });
+        inv.transpose_assign();
+        if !inv.iter().flatten().all(|x| x.is_finite())
+        {
+            return None
+        }
+        Some(inv)
+    }
+
+    fn solve(&self, rhs: [T; N]) -> Option<[T; N]>
+    where
+        T: Float
+    {
+        let mut a = *self;
+        let (p, _) = lu::lu_assign(&mut a);
+        if lu::triangle_is_singular(&a, true)
+        {
+            return None
+        }
+
+        let x = lu::lu_solve(&a, &p, &rhs);
+        if !x.iter().all(|x| x.is_finite())
+        {
+            return None
+        }
+        Some(x)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::ops::*;
+
+    #[test]
+    fn it_works()
+    {
+        let a = [
+            [0.0, 2.0, 1.0],
+            [1.0, 1.0, 0.0],
+            [3.0, 0.0, 1.0]
+        ];
+
+        let det: f64 = a.determinant();
+        assert!((det - -5.0).abs() < 1e-12);
+
+        let a_inv = a.try_inverse().unwrap();
+        let i = a.mul_matrix(&a_inv);
+
+        for (r, row) in i.into_iter().enumerate()
+        {
+            for (c, x) in row.into_iter().enumerate()
+            {
+                assert!((x - if r == c {1.0} else {0.0}).abs() < 1e-12);
+            }
+        }
+
+        let b = [5.0, 3.0, 4.0];
+        let x = a.solve(b).unwrap();
+        let ax = a.mul_vector(&x);
+
+        for (y, b) in ax.into_iter().zip(b)
+        {
+            assert!((y - b).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn mixed_scales()
+    {
+        let a = [
+            [1e-20, 0.0],
+            [0.0, 1.0]
+        ];
+
+        assert_eq!(a.determinant(), 1e-20);
+        assert_eq!(a.try_inverse(), Some([[1e20, 0.0], [0.0, 1.0]]));
+        assert_eq!(a.solve([1.0, 1.0]), Some([1e20, 1.0]));
+        assert_eq!([[1.0, 0.0], [0.0, 1e-17]].determinant(), 1e-17);
+    }
+
+    #[test]
+    fn rank_deficient()
+    {
+        let a = [
+            [1.0, 2.0, 3.0],
+            [2.0, 4.0, 6.0],
+            [1.0, 0.0, 1.0]
+        ];
+
+        assert_eq!(a.determinant(), 0.0);
+        assert_eq!(a.try_inverse(), None);
+        assert_eq!(a.solve([1.0, 2.0, 3.0]), None);
+    }
+}
\ No newline at end of file
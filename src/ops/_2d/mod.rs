@@ -1,12 +1,22 @@
 moddef::moddef!(
+    pub flat(pub) mod {
+        decompose
+    },
     flat(pub) mod {
         diagonal,
         enumerate_zip_kronecker_with,
         flatten,
+        inverse,
         join,
         mul_kronecker,
+        mul_matrix,
+        stack,
+        submatrix,
+        transform_assign,
+        transform,
         transpose_assign,
         transpose,
+        triangular,
         uncollumn,
         zip_kronecker_with,
         zip_kronecker
@@ -0,0 +1,222 @@
+use core::ops::{AddAssign, Mul};
+
+use array_trait::Array;
+use slice_ops::AsSlice;
+
+use crate::form::ArrayForm;
+
+#[const_trait]
+pub trait ArrayMulMatrix<T, const M: usize, const N: usize>: Array + AsSlice<Item = [T; N]>
+{
+    /// Multiplies two two-dimensional arrays (as if they were matrices).
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6]
+    /// ];
+    /// let b = [
+    ///     [1, 0],
+    ///     [0, 1],
+    ///     [1, 1]
+    /// ];
+    /// 
+    /// assert_eq!(a.mul_matrix(&b), [
+    ///     [4, 5],
+    ///     [10, 11]
+    /// ]);
+    /// ```
+    fn mul_matrix<Rhs, U, const P: usize>(&self, rhs: &Rhs) -> [[<T as Mul<U>>::Output; P]; M]
+    where
+        T: Mul<U, Output: AddAssign + Default> + Copy,
+        U: Copy,
+        Rhs: ArrayForm<N, Elem: ArrayForm<P, Elem = U>>;
+    /// Multiplies a two-dimensional array with a column-vector (as if it were a matrix).
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6]
+    /// ];
+    /// 
+    /// assert_eq!(a.mul_vector(&[1, 0, 1]), [4, 10]);
+    /// ```
+    fn mul_vector<Rhs>(&self, rhs: &Rhs) -> [<T as Mul<Rhs::Elem>>::Output; M]
+    where
+        T: Mul<Rhs::Elem, Output: AddAssign + Default> + Copy,
+        Rhs: ArrayForm<N, Elem: Copy>;
+    /// Multiplies a row-vector with a two-dimensional array (as if it were a matrix).
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6]
+    /// ];
+    /// 
+    /// assert_eq!(a.rmul_vector(&[1, 1]), [5, 7, 9]);
+    /// ```
+    fn rmul_vector<Lhs>(&self, lhs: &Lhs) -> [<Lhs::Elem as Mul<T>>::Output; N]
+    where
+        T: Copy,
+        Lhs: ArrayForm<M, Elem: Mul<T, Output: AddAssign + Default> + Copy>;
+
+    async fn mul_matrix_async<Rhs, U, const P: usize>(&self, rhs: &Rhs) -> [[<T as Mul<U>>::Output; P]; M]
+    where
+        T: Mul<U, Output: AddAssign + Default> + Copy,
+        U: Copy,
+        Rhs: ArrayForm<N, Elem: ArrayForm<P, Elem = U>>;
+    async fn mul_vector_async<Rhs>(&self, rhs: &Rhs) -> [<T as Mul<Rhs::Elem>>::Output; M]
+    where
+        T: Mul<Rhs::Elem, Output: AddAssign + Default> + Copy,
+        Rhs: ArrayForm<N, Elem: Copy>;
+    async fn rmul_vector_async<Lhs>(&self, lhs: &Lhs) -> [<Lhs::Elem as Mul<T>>::Output; N]
+    where
+        T: Copy,
+        Lhs: ArrayForm<M, Elem: Mul<T, Output: AddAssign + Default> + Copy>;
+}
+
+impl<T, const M: usize, const N: usize> ArrayMulMatrix<T, M, N> for [[T; N]; M]
+{
+    fn mul_matrix<Rhs, U, const P: usize>(&self, rhs: &Rhs) -> [[<T as Mul<U>>::Output; P]; M]
+    where
+        T: Mul<U, Output: AddAssign + Default> + Copy,
+        U: Copy,
+        Rhs: ArrayForm<N, Elem: ArrayForm<P, Elem = U>>
+    {
+        crate::from_fn(|i| crate::from_fn(|j| r#impl::mul_row_collumn(self, rhs, i, j)))
+    }
+    fn mul_vector<Rhs>(&self, rhs: &Rhs) -> [<T as Mul<Rhs::Elem>>::Output; M]
+    where
+        T: Mul<Rhs::Elem, Output: AddAssign + Default> + Copy,
+        Rhs: ArrayForm<N, Elem: Copy>
+    {
+        crate::from_fn(|i| r#impl::mul_row_vector(self, rhs, i))
+    }
+    fn rmul_vector<Lhs>(&self, lhs: &Lhs) -> [<Lhs::Elem as Mul<T>>::Output; N]
+    where
+        T: Copy,
+        Lhs: ArrayForm<M, Elem: Mul<T, Output: AddAssign + Default> + Copy>
+    {
+        crate::from_fn(|j| r#impl::mul_vector_collumn(lhs, self, j))
+    }
+
+    async fn mul_matrix_async<Rhs, U, const P: usize>(&self, rhs: &Rhs) -> [[<T as Mul<U>>::Output; P]; M]
+    where
+        T: Mul<U, Output: AddAssign + Default> + Copy,
+        U: Copy,
+        Rhs: ArrayForm<N, Elem: ArrayForm<P, Elem = U>>
+    {
+        crate::from_fn_async(async |i| crate::from_fn_async(async |j| r#impl::mul_row_collumn(self, rhs, i, j)).await).await
+    }
+    async fn mul_vector_async<Rhs>(&self, rhs: &Rhs) -> [<T as Mul<Rhs::Elem>>::Output; M]
+    where
+        T: Mul<Rhs::Elem, Output: AddAssign + Default> + Copy,
+        Rhs: ArrayForm<N, Elem: Copy>
+    {
+        crate::from_fn_async(async |i| r#impl::mul_row_vector(self, rhs, i)).await
+    }
+    async fn rmul_vector_async<Lhs>(&self, lhs: &Lhs) -> [<Lhs::Elem as Mul<T>>::Output; N]
+    where
+        T: Copy,
+        Lhs: ArrayForm<M, Elem: Mul<T, Output: AddAssign + Default> + Copy>
+    {
+        crate::from_fn_async(async |j| r#impl::mul_vector_collumn(lhs, self, j)).await
+    }
+}
+
+mod r#impl
+{
+    use core::ops::{AddAssign, Mul};
+
+    use crate::form::ArrayForm;
+
+    pub(super) fn mul_row_collumn<T, U, Rhs, const M: usize, const N: usize, const P: usize>(lhs: &[[T; N]; M], rhs: &Rhs, i: usize, j: usize) -> <T as Mul<U>>::Output
+    where
+        T: Mul<U, Output: AddAssign + Default> + Copy,
+        U: Copy,
+        Rhs: ArrayForm<N, Elem: ArrayForm<P, Elem = U>>
+    {
+        let mut y = Default::default();
+        let mut k = 0;
+        while k < N
+        {
+            y += lhs[i][k]*rhs.copy_elem_2d(k, j);
+            k += 1;
+        }
+        y
+    }
+
+    pub(super) fn mul_row_vector<T, Rhs, const M: usize, const N: usize>(lhs: &[[T; N]; M], rhs: &Rhs, i: usize) -> <T as Mul<Rhs::Elem>>::Output
+    where
+        T: Mul<Rhs::Elem, Output: AddAssign + Default> + Copy,
+        Rhs: ArrayForm<N, Elem: Copy>
+    {
+        let mut y = Default::default();
+        let mut k = 0;
+        while k < N
+        {
+            y += lhs[i][k]*rhs.copy_elem(k);
+            k += 1;
+        }
+        y
+    }
+
+    pub(super) fn mul_vector_collumn<T, Lhs, const M: usize, const N: usize>(lhs: &Lhs, rhs: &[[T; N]; M], j: usize) -> <Lhs::Elem as Mul<T>>::Output
+    where
+        T: Copy,
+        Lhs: ArrayForm<M, Elem: Mul<T, Output: AddAssign + Default> + Copy>
+    {
+        let mut y = Default::default();
+        let mut k = 0;
+        while k < M
+        {
+            y += lhs.copy_elem(k)*rhs[k][j];
+            k += 1;
+        }
+        y
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::ops::*;
+
+    #[test]
+    fn it_works()
+    {
+        let a = [
+            [1, 2],
+            [3, 4]
+        ];
+        let b = [
+            [5, 6, 7],
+            [8, 9, 10]
+        ];
+
+        let c = a.mul_matrix(&b);
+
+        assert_eq!(c, [
+            [21, 24, 27],
+            [47, 54, 61]
+        ]);
+
+        tokio_test::block_on(async {
+            assert_eq!(a.mul_matrix_async(&b).await, c);
+            assert_eq!(a.mul_vector_async(&[1, 1]).await, [3, 7]);
+            assert_eq!(b.rmul_vector_async(&[1, 1]).await, [13, 15, 17]);
+        });
+    }
+}
\ No newline at end of file
@@ -0,0 +1,212 @@
+use core::mem::ManuallyDrop;
+
+use array_trait::Array;
+use slice_ops::AsSlice;
+
+use crate::ops::{ArrayChain, ArrayZipWith};
+
+#[const_trait]
+pub trait ArrayStack<T, const M: usize, const N: usize>: Array + AsSlice<Item = [T; N]>
+{
+    /// Concatenates two matrices side by side, where each row of the result is a row of `self` followed by the corresponding row of `rhs`.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [
+    ///     [1, 2],
+    ///     [3, 4]
+    /// ];
+    /// let b = [
+    ///     [5],
+    ///     [6]
+    /// ];
+    /// 
+    /// assert_eq!(a.hstack(b), [
+    ///     [1, 2, 5],
+    ///     [3, 4, 6]
+    /// ]);
+    /// ```
+    fn hstack<const P: usize>(self, rhs: [[T; P]; M]) -> [[T; N + P]; M];
+    /// Concatenates two matrices on top of each other, where the rows of `self` are followed by the rows of `rhs`.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [
+    ///     [1, 2],
+    ///     [3, 4]
+    /// ];
+    /// let b = [
+    ///     [5, 6]
+    /// ];
+    /// 
+    /// assert_eq!(a.vstack(b), [
+    ///     [1, 2],
+    ///     [3, 4],
+    ///     [5, 6]
+    /// ]);
+    /// ```
+    fn vstack<const P: usize>(self, rhs: [[T; N]; P]) -> [[T; N]; M + P];
+    /// Places two matrices along the diagonal of a block matrix, filling the off-diagonal blocks with `zero`.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [
+    ///     [1, 2],
+    ///     [3, 4]
+    /// ];
+    /// let b = [
+    ///     [5]
+    /// ];
+    /// 
+    /// assert_eq!(a.block_diag(b, 0), [
+    ///     [1, 2, 0],
+    ///     [3, 4, 0],
+    ///     [0, 0, 5]
+    /// ]);
+    /// ```
+    fn block_diag<const P: usize, const Q: usize>(self, rhs: [[T; Q]; P], zero: T) -> [[T; N + Q]; M + P]
+    where
+        T: Clone;
+    /// Splits a matrix into four blocks, at row `R` and collumn `C`.
+    /// 
+    /// Returns the top-left, top-right, bottom-left and bottom-right blocks, in that order.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    ///     [7, 8, 9]
+    /// ];
+    /// 
+    /// let (a11, a12, a21, a22) = a.split_blocks::<1, 2>();
+    /// 
+    /// assert_eq!(a11, [[1, 2]]);
+    /// assert_eq!(a12, [[3]]);
+    /// assert_eq!(a21, [[4, 5], [7, 8]]);
+    /// assert_eq!(a22, [[6], [9]]);
+    /// ```
+    fn split_blocks<const R: usize, const C: usize>(self) -> ([[T; C]; R], [[T; N - C]; R], [[T; C]; M - R], [[T; N - C]; M - R])
+    where
+        [(); M - R]:,
+        [(); N - C]:;
+}
+
+impl<T, const M: usize, const N: usize> ArrayStack<T, M, N> for [[T; N]; M]
+{
+    fn hstack<const P: usize>(self, rhs: [[T; P]; M]) -> [[T; N + P]; M]
+    {
+        self.zip_with(rhs, |lhs, rhs| lhs.chain(rhs))
+    }
+    fn vstack<const P: usize>(self, rhs: [[T; N]; P]) -> [[T; N]; M + P]
+    {
+        self.chain(rhs)
+    }
+    fn block_diag<const P: usize, const Q: usize>(self, rhs: [[T; Q]; P], zero: T) -> [[T; N + Q]; M + P]
+    where
+        T: Clone
+    {
+        let top = self.map(|row| row.chain::<Q>(crate::from_fn(|_| zero.clone())));
+        let bottom = rhs.map(|row| crate::from_fn::<T, N, _>(|_| zero.clone()).chain(row));
+        top.chain(bottom)
+    }
+    fn split_blocks<const R: usize, const C: usize>(self) -> ([[T; C]; R], [[T; N - C]; R], [[T; C]; M - R], [[T; N - C]; M - R])
+    where
+        [(); M - R]:,
+        [(); N - C]:
+    {
+        // Every element is moved out exactly once, and nothing here can panic
+        let this = ManuallyDrop::new(self);
+        unsafe {(
+            crate::from_fn(|r| crate::from_fn(|c| core::ptr::read(&this[r][c]))),
+            crate::from_fn(|r| crate::from_fn(|c| core::ptr::read(&this[r][C + c]))),
+            crate::from_fn(|r| crate::from_fn(|c| core::ptr::read(&this[R + r][c]))),
+            crate::from_fn(|r| crate::from_fn(|c| core::ptr::read(&this[R + r][C + c])))
+        )}
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use std::{cell::Cell, panic, rc::Rc};
+
+    use crate::ops::*;
+
+    #[test]
+    fn it_works()
+    {
+        let a = [[1, 2, 3], [4, 5, 6]];
+        let b = [[7, 8, 9]];
+        let c = [[10], [11], [12]];
+
+        let d = a.vstack(b).hstack(c);
+        let (a2, c2, b2, c3) = d.split_blocks::<2, 3>();
+        assert_eq!(a2, a);
+        assert_eq!(b2, b);
+        assert_eq!(c2.vstack(c3), c);
+    }
+
+    #[test]
+    fn block_diag_panic_safety()
+    {
+        // Counts live values, and panics on the fourth clone
+        struct Counted(Rc<Cell<(usize, usize)>>);
+        impl Clone for Counted
+        {
+            fn clone(&self) -> Self
+            {
+                let (live, clones) = self.0.get();
+                if clones == 3
+                {
+                    panic!()
+                }
+                self.0.set((live + 1, clones + 1));
+                Counted(self.0.clone())
+            }
+        }
+        impl Drop for Counted
+        {
+            fn drop(&mut self)
+            {
+                let (live, clones) = self.0.get();
+                self.0.set((live - 1, clones));
+            }
+        }
+
+        let count = Rc::new(Cell::new((0, 0)));
+        let new = || {
+            let (live, clones) = count.get();
+            count.set((live + 1, clones));
+            Counted(count.clone())
+        };
+        let a = [[new(), new()], [new(), new()]];
+        let b = [[new()]];
+        let zero = new();
+
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| a.block_diag(b, zero)));
+
+        assert!(result.is_err());
+        assert_eq!(count.get(), (0, 3));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,254 @@
+use array_trait::Array;
+use slice_ops::AsSlice;
+
+#[const_trait]
+pub trait ArraySubmatrix<T, const M: usize, const N: usize>: Array + AsSlice<Item = [T; N]>
+{
+    /// Copies out the `R` by `C` sub-matrix starting at row `R0` and collumn `C0`.
+    /// 
+    /// The sub-matrix must fit within the matrix, which is checked at compile-time.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [
+    ///     [1, 2, 3, 4],
+    ///     [5, 6, 7, 8],
+    ///     [9, 10, 11, 12]
+    /// ];
+    /// 
+    /// assert_eq!(a.submatrix::<1, 1, 2, 2>(), [
+    ///     [6, 7],
+    ///     [10, 11]
+    /// ]);
+    /// ```
+    fn submatrix<const R0: usize, const C0: usize, const R: usize, const C: usize>(&self) -> [[T; C]; R]
+    where
+        T: Copy,
+        [(); M - R0 - R]:,
+        [(); N - C0 - C]:;
+    /// Borrows the `R` by `C` sub-matrix starting at row `R0` and collumn `C0`, as an array of row references.
+    /// 
+    /// The sub-matrix must fit within the matrix, which is checked at compile-time.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [
+    ///     ["a", "b", "c"],
+    ///     ["d", "e", "f"]
+    /// ];
+    /// 
+    /// assert_eq!(a.submatrix_ref::<0, 1, 2, 2>(), [
+    ///     &["b", "c"],
+    ///     &["e", "f"]
+    /// ]);
+    /// ```
+    fn submatrix_ref<const R0: usize, const C0: usize, const R: usize, const C: usize>(&self) -> [&[T; C]; R]
+    where
+        [(); M - R0 - R]:,
+        [(); N - C0 - C]:;
+    /// Mutably borrows the `R` by `C` sub-matrix starting at row `R0` and collumn `C0`, as an array of row references.
+    /// 
+    /// The sub-matrix must fit within the matrix, which is checked at compile-time.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let mut a = [[0; 4]; 3];
+    /// 
+    /// for row in a.submatrix_mut::<1, 2, 2, 2>()
+    /// {
+    ///     row.fill(1);
+    /// }
+    /// 
+    /// assert_eq!(a, [
+    ///     [0, 0, 0, 0],
+    ///     [0, 0, 1, 1],
+    ///     [0, 0, 1, 1]
+    /// ]);
+    /// ```
+    fn submatrix_mut<const R0: usize, const C0: usize, const R: usize, const C: usize>(&mut self) -> [&mut [T; C]; R]
+    where
+        [(); M - R0 - R]:,
+        [(); N - C0 - C]:;
+    /// Overwrites the sub-matrix starting at row `R0` and collumn `C0`.
+    /// 
+    /// The sub-matrix must fit within the matrix, which is checked at compile-time.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let mut a = [[0; 3]; 3];
+    /// 
+    /// a.set_submatrix::<0, 1, 2, 2>([
+    ///     [1, 2],
+    ///     [3, 4]
+    /// ]);
+    /// 
+    /// assert_eq!(a, [
+    ///     [0, 1, 2],
+    ///     [0, 3, 4],
+    ///     [0, 0, 0]
+    /// ]);
+    /// ```
+    fn set_submatrix<const R0: usize, const C0: usize, const R: usize, const C: usize>(&mut self, submatrix: [[T; C]; R])
+    where
+        [(); M - R0 - R]:,
+        [(); N - C0 - C]:;
+
+    /// Copies out the matrix with row `i` and collumn `j` removed.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `i` or `j` are out of bounds.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    ///     [7, 8, 9]
+    /// ];
+    /// 
+    /// assert_eq!(a.minor(1, 0), [
+    ///     [2, 3],
+    ///     [8, 9]
+    /// ]);
+    /// ```
+    fn minor(&self, i: usize, j: usize) -> [[T; N - 1]; M - 1]
+    where
+        T: Copy,
+        [(); M - 1]:,
+        [(); N - 1]:;
+}
+
+impl<T, const M: usize, const N: usize> ArraySubmatrix<T, M, N> for [[T; N]; M]
+{
+    fn submatrix<const R0: usize, const C0: usize, const R: usize, const C: usize>(&self) -> [[T; C]; R]
+    where
+        T: Copy,
+        [(); M - R0 - R]:,
+        [(); N - C0 - C]:
+    {
+        crate::from_fn(|r| unsafe {
+            *r#impl::row_ref::<T, N, C0, C>(&self[R0 + r])
+        })
+    }
+    fn submatrix_ref<const R0: usize, const C0: usize, const R: usize, const C: usize>(&self) -> [&[T; C]; R]
+    where
+        [(); M - R0 - R]:,
+        [(); N - C0 - C]:
+    {
+        crate::from_fn(|r| unsafe {
+            r#impl::row_ref::<T, N, C0, C>(&self[R0 + r])
+        })
+    }
+    fn submatrix_mut<const R0: usize, const C0: usize, const R: usize, const C: usize>(&mut self) -> [&mut [T; C]; R]
+    where
+        [(); M - R0 - R]:,
+        [(); N - C0 - C]:
+    {
+        let rows = self.as_mut_ptr();
+        crate::from_fn(|r| unsafe {
+            // Each row is only borrowed once
+            r#impl::row_mut::<T, N, C0, C>(rows.add(R0 + r).as_mut_unchecked())
+        })
+    }
+    fn set_submatrix<const R0: usize, const C0: usize, const R: usize, const C: usize>(&mut self, submatrix: [[T; C]; R])
+    where
+        [(); M - R0 - R]:,
+        [(); N - C0 - C]:
+    {
+        for (dst, src) in self.submatrix_mut::<R0, C0, R, C>().into_iter().zip(submatrix)
+        {
+            *dst = src;
+        }
+    }
+
+    fn minor(&self, i: usize, j: usize) -> [[T; N - 1]; M - 1]
+    where
+        T: Copy,
+        [(); M - 1]:,
+        [(); N - 1]:
+    {
+        assert!(i < M && j < N, "index out of bounds");
+        crate::from_fn(|r| {
+            let row = &self[r + (r >= i) as usize];
+            crate::from_fn(|c| row[c + (c >= j) as usize])
+        })
+    }
+}
+
+mod r#impl
+{
+    /// Caller must ensure `C0 + C <= N`.
+    pub(super) unsafe fn row_ref<T, const N: usize, const C0: usize, const C: usize>(row: &[T; N]) -> &[T; C]
+    {
+        row.as_ptr().add(C0).cast::<[T; C]>().as_ref_unchecked()
+    }
+    /// Caller must ensure `C0 + C <= N`.
+    pub(super) unsafe fn row_mut<T, const N: usize, const C0: usize, const C: usize>(row: &mut [T; N]) -> &mut [T; C]
+    {
+        row.as_mut_ptr().add(C0).cast::<[T; C]>().as_mut_unchecked()
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::ops::*;
+
+    #[test]
+    fn it_works()
+    {
+        let a = [
+            [1, 2, 3, 4],
+            [5, 6, 7, 8],
+            [9, 10, 11, 12]
+        ];
+
+        assert_eq!(a.submatrix::<0, 0, 3, 4>(), a);
+        assert_eq!(a.submatrix::<0, 0, 1, 1>(), [[1]]);
+        assert_eq!(a.submatrix::<2, 3, 1, 1>(), [[12]]);
+        assert_eq!(a.submatrix::<1, 2, 2, 2>(), [[7, 8], [11, 12]]);
+        assert_eq!(a.submatrix::<3, 4, 0, 0>(), [] as [[i32; 0]; 0]);
+        assert_eq!(a.submatrix_ref::<0, 3, 3, 1>(), [&[4], &[8], &[12]]);
+
+        let mut b = a;
+        for row in b.submatrix_mut::<2, 0, 1, 4>()
+        {
+            row.fill(0);
+        }
+        b.set_submatrix::<0, 3, 2, 1>([[0], [0]]);
+        assert_eq!(b, [
+            [1, 2, 3, 0],
+            [5, 6, 7, 0],
+            [0, 0, 0, 0]
+        ]);
+
+        assert_eq!(a.minor(0, 0), [[6, 7, 8], [10, 11, 12]]);
+        assert_eq!(a.minor(2, 3), [[1, 2, 3], [5, 6, 7]]);
+    }
+}
\ No newline at end of file
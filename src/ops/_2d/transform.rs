@@ -0,0 +1,194 @@
+use array_trait::Array;
+use slice_ops::AsSlice;
+
+use super::ArrayTranspose;
+
+#[const_trait]
+pub trait ArrayTransform<T, const M: usize, const N: usize>: Array + AsSlice<Item = [T; N]>
+{
+    /// Rotates a two-dimensional array a quarter turn clockwise.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6]
+    /// ];
+    /// 
+    /// assert_eq!(a.rotate_90(), [
+    ///     [4, 1],
+    ///     [5, 2],
+    ///     [6, 3]
+    /// ]);
+    /// ```
+    fn rotate_90(self) -> [[T; M]; N];
+    /// Rotates a two-dimensional array a half turn.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6]
+    /// ];
+    /// 
+    /// assert_eq!(a.rotate_180(), [
+    ///     [6, 5, 4],
+    ///     [3, 2, 1]
+    /// ]);
+    /// ```
+    fn rotate_180(self) -> [[T; N]; M];
+    /// Rotates a two-dimensional array a quarter turn counter-clockwise.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6]
+    /// ];
+    /// 
+    /// assert_eq!(a.rotate_270(), [
+    ///     [3, 6],
+    ///     [2, 5],
+    ///     [1, 4]
+    /// ]);
+    /// ```
+    fn rotate_270(self) -> [[T; M]; N];
+    /// Reverses the order of the rows, mirroring the array vertically.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6]
+    /// ];
+    /// 
+    /// assert_eq!(a.flip_rows(), [
+    ///     [4, 5, 6],
+    ///     [1, 2, 3]
+    /// ]);
+    /// ```
+    fn flip_rows(self) -> [[T; N]; M];
+    /// Reverses the order of the collumns, mirroring the array horizontally.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6]
+    /// ];
+    /// 
+    /// assert_eq!(a.flip_cols(), [
+    ///     [3, 2, 1],
+    ///     [6, 5, 4]
+    /// ]);
+    /// ```
+    fn flip_cols(self) -> [[T; N]; M];
+    /// Transposes a two-dimensional array along the anti-diagonal.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6]
+    /// ];
+    /// 
+    /// assert_eq!(a.anti_transpose(), [
+    ///     [6, 3],
+    ///     [5, 2],
+    ///     [4, 1]
+    /// ]);
+    /// ```
+    fn anti_transpose(self) -> [[T; M]; N];
+
+    /// Rotates a two-dimensional array a half turn in-place.
+    fn rotate_180_assign(&mut self);
+    /// Reverses the order of the rows in-place, mirroring the array vertically.
+    fn flip_rows_assign(&mut self);
+    /// Reverses the order of the collumns in-place, mirroring the array horizontally.
+    fn flip_cols_assign(&mut self);
+}
+
+impl<T, const M: usize, const N: usize> const ArrayTransform<T, M, N> for [[T; N]; M]
+{
+    fn rotate_90(self) -> [[T; M]; N]
+    {
+        let mut rotated = self.transpose();
+        rotated.flip_cols_assign();
+        rotated
+    }
+    fn rotate_180(mut self) -> [[T; N]; M]
+    {
+        self.rotate_180_assign();
+        self
+    }
+    fn rotate_270(self) -> [[T; M]; N]
+    {
+        let mut rotated = self.transpose();
+        rotated.flip_rows_assign();
+        rotated
+    }
+    fn flip_rows(mut self) -> [[T; N]; M]
+    {
+        self.flip_rows_assign();
+        self
+    }
+    fn flip_cols(mut self) -> [[T; N]; M]
+    {
+        self.flip_cols_assign();
+        self
+    }
+    fn anti_transpose(self) -> [[T; M]; N]
+    {
+        let mut transposed = self.transpose();
+        transposed.rotate_180_assign();
+        transposed
+    }
+
+    fn rotate_180_assign(&mut self)
+    {
+        self.flip_rows_assign();
+        self.flip_cols_assign();
+    }
+    fn flip_rows_assign(&mut self)
+    {
+        let mut r = 0;
+        while r < M/2
+        {
+            self.swap(r, M - 1 - r);
+            r += 1;
+        }
+    }
+    fn flip_cols_assign(&mut self)
+    {
+        let mut r = 0;
+        while r < M
+        {
+            let row = &mut self[r];
+            let mut c = 0;
+            while c < N/2
+            {
+                row.swap(c, N - 1 - c);
+                c += 1;
+            }
+            r += 1;
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,140 @@
+use array_trait::Array;
+use slice_ops::AsSlice;
+
+use super::{ArrayTransform, ArrayTransposeAssign};
+
+#[const_trait]
+pub trait ArrayTransformAssign<T, const N: usize>: Array + AsSlice<Item = [T; N]>
+{
+    /// Rotates a square matrix a quarter turn clockwise in-place.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let mut a = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    ///     [7, 8, 9]
+    /// ];
+    /// 
+    /// a.rotate_90_assign();
+    /// 
+    /// assert_eq!(a, [
+    ///     [7, 4, 1],
+    ///     [8, 5, 2],
+    ///     [9, 6, 3]
+    /// ]);
+    /// ```
+    fn rotate_90_assign(&mut self);
+    /// Rotates a square matrix a quarter turn counter-clockwise in-place.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let mut a = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    ///     [7, 8, 9]
+    /// ];
+    /// 
+    /// a.rotate_270_assign();
+    /// 
+    /// assert_eq!(a, [
+    ///     [3, 6, 9],
+    ///     [2, 5, 8],
+    ///     [1, 4, 7]
+    /// ]);
+    /// ```
+    fn rotate_270_assign(&mut self);
+    /// Transposes a square matrix along the anti-diagonal in-place.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let mut a = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    ///     [7, 8, 9]
+    /// ];
+    /// 
+    /// a.anti_transpose_assign();
+    /// 
+    /// assert_eq!(a, [
+    ///     [9, 6, 3],
+    ///     [8, 5, 2],
+    ///     [7, 4, 1]
+    /// ]);
+    /// ```
+    fn anti_transpose_assign(&mut self);
+}
+
+impl<T, const N: usize> const ArrayTransformAssign<T, N> for [[T; N]; N]
+{
+    fn rotate_90_assign(&mut self)
+    {
+        self.transpose_assign();
+        self.flip_cols_assign();
+    }
+    fn rotate_270_assign(&mut self)
+    {
+        self.transpose_assign();
+        self.flip_rows_assign();
+    }
+    fn anti_transpose_assign(&mut self)
+    {
+        let mut r = 0;
+        while r < N
+        {
+            let row = unsafe {
+                (&mut self[r] as *mut [T; N]).as_mut_unchecked()
+            };
+            let mut c = 0;
+            while c + r + 1 < N
+            {
+                core::mem::swap(
+                    &mut row[c],
+                    &mut self[N - 1 - c][N - 1 - r]
+                );
+                c += 1;
+            }
+            r += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::ops::*;
+
+    #[test]
+    fn it_works()
+    {
+        let a = [
+            [1, 2, 3, 4],
+            [5, 6, 7, 8],
+            [9, 10, 11, 12],
+            [13, 14, 15, 16]
+        ];
+
+        let mut b = a;
+        b.rotate_90_assign();
+        assert_eq!(b, a.rotate_90());
+        b.rotate_90_assign();
+        assert_eq!(b, a.rotate_180());
+        b.rotate_90_assign();
+        assert_eq!(b, a.rotate_270());
+        b.rotate_90_assign();
+        assert_eq!(b, a);
+
+        b.anti_transpose_assign();
+        assert_eq!(b, a.anti_transpose());
+        assert_eq!(b, a.transpose().rotate_180());
+    }
+}
\ No newline at end of file
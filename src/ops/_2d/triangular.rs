@@ -0,0 +1,325 @@
+use array_trait::Array;
+use num_traits::Float;
+use slice_ops::AsSlice;
+
+use crate::private::lu;
+
+#[const_trait]
+pub trait ArrayTriangular<T, const N: usize>: Array + AsSlice<Item = [T; N]>
+{
+    /// Keeps the elements on and above the `k`-th diagonal, and replaces the rest with `fill`.
+    /// 
+    /// `k = 0` is the main diagonal, `k > 0` is above it and `k < 0` is below it.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    ///     [7, 8, 9]
+    /// ];
+    /// 
+    /// assert_eq!(a.triu(0, 0), [
+    ///     [1, 2, 3],
+    ///     [0, 5, 6],
+    ///     [0, 0, 9]
+    /// ]);
+    /// assert_eq!(a.triu(1, 0), [
+    ///     [0, 2, 3],
+    ///     [0, 0, 6],
+    ///     [0, 0, 0]
+    /// ]);
+    /// ```
+    fn triu(self, k: isize, fill: T) -> [[T; N]; N]
+    where
+        T: Clone;
+    /// Keeps the elements on and below the `k`-th diagonal, and replaces the rest with `fill`.
+    /// 
+    /// `k = 0` is the main diagonal, `k > 0` is above it and `k < 0` is below it.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    ///     [7, 8, 9]
+    /// ];
+    /// 
+    /// assert_eq!(a.tril(-1, 0), [
+    ///     [0, 0, 0],
+    ///     [4, 0, 0],
+    ///     [7, 8, 0]
+    /// ]);
+    /// ```
+    fn tril(self, k: isize, fill: T) -> [[T; N]; N]
+    where
+        T: Clone;
+
+    /// Solves `Ux = b` for `x` by back substitution, where `U` is this matrix's upper triangle. The lower triangle is ignored.
+    /// 
+    /// Returns `None` if the diagonal contains an exact zero, or if the triangle contains a value that isn't finite.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let u = [
+    ///     [2.0, 1.0],
+    ///     [0.0, 4.0]
+    /// ];
+    /// 
+    /// assert_eq!(u.solve_upper([4.0, 8.0]), Some([1.0, 2.0]));
+    /// ```
+    fn solve_upper(&self, rhs: [T; N]) -> Option<[T; N]>
+    where
+        T: Float;
+    /// Solves `Lx = b` for `x` by forward substitution, where `L` is this matrix's lower triangle. The upper triangle is ignored.
+    /// 
+    /// Returns `None` if the diagonal contains an exact zero, or if the triangle contains a value that isn't finite.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::ops::*;
+    /// 
+    /// let l = [
+    ///     [2.0, 0.0],
+    ///     [1.0, 4.0]
+    /// ];
+    /// 
+    /// assert_eq!(l.solve_lower([2.0, 9.0]), Some([1.0, 2.0]));
+    /// ```
+    fn solve_lower(&self, rhs: [T; N]) -> Option<[T; N]>
+    where
+        T: Float;
+
+    /// Packs the upper triangle (including the diagonal) row by row, dropping the lower triangle.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let a = [
+    ///     [1, 2, 3],
+    ///     [2, 4, 5],
+    ///     [3, 5, 6]
+    /// ];
+    /// 
+    /// let packed = a.pack_upper();
+    /// 
+    /// assert_eq!(packed, [1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(<[[_; 3]; 3]>::unpack_symmetric(&packed), a);
+    /// ```
+    fn pack_upper(&self) -> [T; N*(N + 1)/2]
+    where
+        T: Copy,
+        [(); N*(N + 1)/2]:;
+    /// Unpacks an upper triangle packed row by row, filling the lower triangle with `fill`.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::ops::*;
+    /// 
+    /// let packed = [1, 2, 3, 4, 5, 6];
+    /// 
+    /// assert_eq!(<[[_; 3]; 3]>::unpack_upper(&packed, 0), [
+    ///     [1, 2, 3],
+    ///     [0, 4, 5],
+    ///     [0, 0, 6]
+    /// ]);
+    /// ```
+    fn unpack_upper(packed: &[T; N*(N + 1)/2], fill: T) -> [[T; N]; N]
+    where
+        T: Copy,
+        [(); N*(N + 1)/2]:;
+    /// Unpacks an upper triangle packed row by row, mirroring it into the lower triangle to form a symmetric matrix.
+    fn unpack_symmetric(packed: &[T; N*(N + 1)/2]) -> [[T; N]; N]
+    where
+        T: Copy,
+        [(); N*(N + 1)/2]:;
+}
+
+impl<T, const N: usize> ArrayTriangular<T, N> for [[T; N]; N]
+{
+    fn triu(mut self, k: isize, fill: T) -> [[T; N]; N]
+    where
+        T: Clone
+    {
+        let mut i = 0;
+        while i < N
+        {
+            let mut j = 0;
+            while j < N
+            {
+                if (j as isize) - (i as isize) < k
+                {
+                    self[i][j] = fill.clone();
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+        self
+    }
+    fn tril(mut self, k: isize, fill: T) -> [[T; N]; N]
+    where
+        T: Clone
+    {
+        let mut i = 0;
+        while i < N
+        {
+            let mut j = 0;
+            while j < N
+            {
+                if (j as isize) - (i as isize) > k
+                {
+                    self[i][j] = fill.clone();
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+        self
+    }
+
+    fn solve_upper(&self, mut rhs: [T; N]) -> Option<[T; N]>
+    where
+        T: Float
+    {
+        if lu::triangle_is_singular(self, true)
+        {
+            return None
+        }
+        lu::back_substitute_assign(self, &mut rhs);
+        Some(rhs)
+    }
+    fn solve_lower(&self, mut rhs: [T; N]) -> Option<[T; N]>
+    where
+        T: Float
+    {
+        if lu::triangle_is_singular(self, false)
+        {
+            return None
+        }
+        lu::forward_substitute_assign(self, &mut rhs, false);
+        Some(rhs)
+    }
+
+    fn pack_upper(&self) -> [T; N*(N + 1)/2]
+    where
+        T: Copy,
+        [(); N*(N + 1)/2]:
+    {
+        let mut i = 0;
+        let mut j = 0;
+        crate::from_fn(|_| {
+            let x = self[i][j];
+            j += 1;
+            if j == N
+            {
+                i += 1;
+                j = i;
+            }
+            x
+        })
+    }
+    fn unpack_upper(packed: &[T; N*(N + 1)/2], fill: T) -> [[T; N]; N]
+    where
+        T: Copy,
+        [(); N*(N + 1)/2]:
+    {
+        crate::from_fn(|i| crate::from_fn(|j| if j >= i
+        {
+            packed[r#impl::packed_index::<N>(i, j)]
+        }
+        else
+        {
+            fill
+        }))
+    }
+    fn unpack_symmetric(packed: &[T; N*(N + 1)/2]) -> [[T; N]; N]
+    where
+        T: Copy,
+        [(); N*(N + 1)/2]:
+    {
+        crate::from_fn(|i| crate::from_fn(|j| if j >= i
+        {
+            packed[r#impl::packed_index::<N>(i, j)]
+        }
+        else
+        {
+            packed[r#impl::packed_index::<N>(j, i)]
+        }))
+    }
+}
+
+mod r#impl
+{
+    /// Index of element `(i, j)` in the packed upper triangle, where `i <= j`.
+    pub(super) const fn packed_index<const N: usize>(i: usize, j: usize) -> usize
+    {
+        // Rows before `i` hold N + (N - 1) + ... + (N - i + 1) elements
+        i*(2*N + 1 - i)/2 + j - i
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::ops::*;
+
+    #[test]
+    fn it_works()
+    {
+        let a = [
+            [4.0, -2.0, 1.0, 3.0],
+            [-2.0, 5.0, 0.5, -1.0],
+            [1.0, 0.5, 6.0, 2.0],
+            [3.0, -1.0, 2.0, 7.0]
+        ];
+        let b = [1.0, 2.0, 3.0, 4.0];
+
+        let u = a.triu(0, 0.0);
+        let l = a.tril(0, 0.0);
+        let x = a.solve_upper(b).unwrap();
+        let y = a.solve_lower(b).unwrap();
+        let ux: [f64; 4] = u.mul_vector(&x);
+        let ly: [f64; 4] = l.mul_vector(&y);
+        for (r, b) in ux.into_iter().zip(b).chain(ly.into_iter().zip(b))
+        {
+            assert!((r - b).abs() < 1e-12);
+        }
+
+        let packed = a.pack_upper();
+        assert_eq!(<[[f64; 4]; 4]>::unpack_symmetric(&packed), a);
+        assert_eq!(<[[f64; 4]; 4]>::unpack_upper(&packed, 0.0), u);
+        assert_eq!(a.triu(1, 0.0).tril(1, 0.0), a.tril(1, 0.0).triu(1, 0.0));
+
+        let mut singular = a;
+        singular[2][2] = 0.0;
+        assert!(singular.solve_upper(b).is_none());
+        assert!(singular.solve_lower(b).is_none());
+
+        // Wildly different scales are fine, as long as the diagonal has no zeros
+        let c = [
+            [1e-20, 1.0],
+            [1.0, 1.0]
+        ];
+        assert_eq!(c.solve_upper([2.0, 1.0]), Some([1e20, 1.0]));
+        assert_eq!(c.solve_lower([1.0, 1.0]), Some([1e20, 1.0 - 1e20]));
+        assert_eq!([[1e-20, 0.0], [0.0, 1.0]].solve_upper([1.0, 1.0]), Some([1e20, 1.0]));
+    }
+}
\ No newline at end of file
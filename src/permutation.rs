@@ -0,0 +1,464 @@
+use core::{cmp::Ordering, iter::FusedIterator};
+
+use crate::{ops::{ArrayPermute, ArrayShuffle, ArraySort}, Rng};
+
+/// The parity of a permutation, i.e. whether it can be made from an even or odd number of swaps.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum Parity
+{
+    Even,
+    Odd
+}
+
+/// A permutation of `N` indices, guaranteed to contain each index exactly once.
+/// 
+/// Applying the permutation to an array moves the element at index `p[i]` to index `i`.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use array__ops::Permutation;
+/// 
+/// let p = Permutation::new([2, 0, 1]).unwrap();
+/// 
+/// let mut a = ['a', 'b', 'c'];
+/// 
+/// p.apply(&mut a);
+/// assert_eq!(a, ['c', 'a', 'b']);
+/// 
+/// p.apply_inverse(&mut a);
+/// assert_eq!(a, ['a', 'b', 'c']);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct Permutation<const N: usize>([usize; N]);
+
+impl<const N: usize> Permutation<N>
+{
+    /// Creates a permutation from indices, or returns `None` if they're out of bounds or not unique.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::Permutation;
+    /// 
+    /// assert!(Permutation::new([1, 2, 0]).is_some());
+    /// assert!(Permutation::new([1, 1, 0]).is_none());
+    /// assert!(Permutation::new([1, 3, 0]).is_none());
+    /// ```
+    pub const fn new(indices: [usize; N]) -> Option<Self>
+    {
+        let mut seen = [false; N];
+        let mut i = 0;
+        while i < N
+        {
+            let j = indices[i];
+            if j >= N || seen[j]
+            {
+                return None
+            }
+            seen[j] = true;
+            i += 1;
+        }
+        Some(Self(indices))
+    }
+
+    /// Creates a permutation from indices, without checking that they're valid.
+    /// 
+    /// # Safety
+    /// 
+    /// Each index from `0` to `N - 1` must appear exactly once.
+    pub const unsafe fn new_unchecked(indices: [usize; N]) -> Self
+    {
+        Self(indices)
+    }
+
+    /// Creates the permutation that leaves everything in place.
+    pub const fn identity() -> Self
+    {
+        let mut indices = [0; N];
+        let mut i = 0;
+        while i < N
+        {
+            indices[i] = i;
+            i += 1;
+        }
+        Self(indices)
+    }
+
+    /// Creates the bit-reverse permutation. Length must be a power of 2.
+    /// 
+    /// Applying it is equivalent to [ArrayPermute::bit_rev_permutation].
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use array__ops::{Permutation, ops::*};
+    /// 
+    /// let mut a = [0, 1, 2, 3, 4, 5, 6, 7];
+    /// let mut b = a;
+    /// 
+    /// Permutation::bit_rev().apply(&mut a);
+    /// b.bit_rev_permutation();
+    /// 
+    /// assert_eq!(a, b);
+    /// ```
+    pub const fn bit_rev() -> Self
+    where
+        [(); slice_ops::is_power_of(N, 2) as usize - 1]:
+    {
+        let mut p = Self::identity();
+        p.0.bit_rev_permutation();
+        p
+    }
+    /// Creates the digit-reverse permutation with a given radix. Length must be a power of the radix.
+    /// 
+    /// Applying it is equivalent to [ArrayPermute::digit_rev_permutation].
+    pub const fn digit_rev<const R: usize>() -> Self
+    where
+        [(); slice_ops::is_power_of(N, R) as usize - 1]:
+    {
+        let mut p = Self::identity();
+        p.0.digit_rev_permutation::<R>();
+        p
+    }
+    /// Creates the grey code permutation. Length must be a power of 2.
+    /// 
+    /// Applying it is equivalent to [ArrayPermute::grey_code_permutation].
+    pub const fn grey_code() -> Self
+    where
+        [(); N.is_power_of_two() as usize - 1]:
+    {
+        let mut p = Self::identity();
+        p.0.grey_code_permutation();
+        p
+    }
+    /// Creates the permutation that stably sorts the given array.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::Permutation;
+    /// 
+    /// let keys = [30, 10, 20];
+    /// let p = Permutation::argsort(&keys);
+    /// 
+    /// let mut values = ["thirty", "ten", "twenty"];
+    /// p.apply(&mut values);
+    /// 
+    /// assert_eq!(values, ["ten", "twenty", "thirty"]);
+    /// ```
+    pub fn argsort<T>(array: &[T; N]) -> Self
+    where
+        T: Ord
+    {
+        Self(array.argsort())
+    }
+    /// Creates the permutation that stably sorts the given array with a comparator function.
+    pub fn argsort_by<T, F>(array: &[T; N], compare: F) -> Self
+    where
+        F: FnMut(&T, &T) -> Ordering
+    {
+        Self(array.argsort_by(compare))
+    }
+
+    /// Creates a uniformly random permutation.
+    pub fn random<R>(rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized
+    {
+        Self(Self::identity().0.shuffled(rng))
+    }
+
+    pub const fn as_array(&self) -> &[usize; N]
+    {
+        &self.0
+    }
+    pub const fn into_array(self) -> [usize; N]
+    {
+        self.0
+    }
+
+    /// Composes two permutations, such that applying the result is equivalent to first applying `other`, then `self`.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::Permutation;
+    /// 
+    /// let p = Permutation::new([1, 2, 0]).unwrap();
+    /// let q = Permutation::new([0, 2, 1]).unwrap();
+    /// 
+    /// let mut a = [1, 2, 3];
+    /// let mut b = a;
+    /// 
+    /// p.compose(&q).apply(&mut a);
+    /// q.apply(&mut b);
+    /// p.apply(&mut b);
+    /// 
+    /// assert_eq!(a, b);
+    /// ```
+    pub const fn compose(&self, other: &Self) -> Self
+    {
+        let mut indices = [0; N];
+        let mut i = 0;
+        while i < N
+        {
+            indices[i] = other.0[self.0[i]];
+            i += 1;
+        }
+        Self(indices)
+    }
+
+    /// Inverts the permutation.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::Permutation;
+    /// 
+    /// let p = Permutation::new([2, 0, 3, 1]).unwrap();
+    /// 
+    /// assert_eq!(p.inverse().as_array(), &[1, 3, 0, 2]);
+    /// assert_eq!(p.compose(&p.inverse()), Permutation::identity());
+    /// ```
+    pub const fn inverse(&self) -> Self
+    {
+        let mut indices = [0; N];
+        let mut i = 0;
+        while i < N
+        {
+            indices[self.0[i]] = i;
+            i += 1;
+        }
+        Self(indices)
+    }
+
+    /// Iterates over the cycles of the permutation, including fixed points.
+    /// 
+    /// Each cycle starts at its smallest index, and the cycles are ordered by their smallest index.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::Permutation;
+    /// 
+    /// let p = Permutation::new([2, 1, 3, 0]).unwrap();
+    /// 
+    /// let mut cycles = p.cycles();
+    /// 
+    /// assert!(cycles.next().unwrap().eq([0, 2, 3]));
+    /// assert!(cycles.next().unwrap().eq([1]));
+    /// assert!(cycles.next().is_none());
+    /// ```
+    pub fn cycles(&self) -> Cycles<'_, N>
+    {
+        Cycles {
+            permutation: self,
+            visited: [false; N],
+            next: 0
+        }
+    }
+
+    /// Computes whether the permutation is even or odd.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use array__ops::{Permutation, Parity};
+    /// 
+    /// assert_eq!(Permutation::new([1, 0, 2]).unwrap().parity(), Parity::Odd);
+    /// assert_eq!(Permutation::new([1, 2, 0]).unwrap().parity(), Parity::Even);
+    /// ```
+    pub const fn parity(&self) -> Parity
+    {
+        // A cycle of length L is made from L - 1 swaps
+        let mut visited = [false; N];
+        let mut swaps = 0;
+        let mut i = 0;
+        while i < N
+        {
+            if !visited[i]
+            {
+                let mut j = self.0[i];
+                visited[i] = true;
+                while j != i
+                {
+                    visited[j] = true;
+                    j = self.0[j];
+                    swaps += 1;
+                }
+            }
+            i += 1;
+        }
+        if swaps % 2 == 0
+        {
+            Parity::Even
+        }
+        else
+        {
+            Parity::Odd
+        }
+    }
+
+    /// Applies the permutation in-place, such that the element at index `p[i]` is moved to index `i`.
+    /// 
+    /// Elements are moved along the cycles of the permutation, using `N` swaps at most.
+    pub const fn apply<T>(&self, array: &mut [T; N])
+    {
+        let mut visited = [false; N];
+        let mut i = 0;
+        while i < N
+        {
+            if !visited[i]
+            {
+                visited[i] = true;
+                let mut j = i;
+                let mut k = self.0[j];
+                while k != i
+                {
+                    visited[k] = true;
+                    array.swap(j, k);
+                    j = k;
+                    k = self.0[j];
+                }
+            }
+            i += 1;
+        }
+    }
+
+    /// Applies the inverse of the permutation in-place, such that the element at index `i` is moved to index `p[i]`.
+    /// 
+    /// Elements are moved along the cycles of the permutation, using `N` swaps at most.
+    pub const fn apply_inverse<T>(&self, array: &mut [T; N])
+    {
+        let mut visited = [false; N];
+        let mut i = 0;
+        while i < N
+        {
+            if !visited[i]
+            {
+                visited[i] = true;
+                let mut k = self.0[i];
+                while k != i
+                {
+                    visited[k] = true;
+                    array.swap(i, k);
+                    k = self.0[k];
+                }
+            }
+            i += 1;
+        }
+    }
+}
+
+impl<const N: usize> Default for Permutation<N>
+{
+    fn default() -> Self
+    {
+        Self::identity()
+    }
+}
+
+impl<const N: usize> From<Permutation<N>> for [usize; N]
+{
+    fn from(permutation: Permutation<N>) -> Self
+    {
+        permutation.into_array()
+    }
+}
+
+/// An iterator over the cycles of a [Permutation].
+#[derive(Clone, Debug)]
+pub struct Cycles<'a, const N: usize>
+{
+    permutation: &'a Permutation<N>,
+    visited: [bool; N],
+    next: usize
+}
+
+impl<'a, const N: usize> Iterator for Cycles<'a, N>
+{
+    type Item = Cycle<'a, N>;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        while self.next < N && self.visited[self.next]
+        {
+            self.next += 1;
+        }
+        if self.next == N
+        {
+            return None
+        }
+
+        let start = self.next;
+        let mut j = start;
+        loop
+        {
+            self.visited[j] = true;
+            j = self.permutation.0[j];
+            if j == start
+            {
+                break
+            }
+        }
+
+        Some(Cycle {
+            permutation: self.permutation,
+            start,
+            next: Some(start)
+        })
+    }
+}
+impl<const N: usize> FusedIterator for Cycles<'_, N> {}
+
+/// An iterator over the indices of one cycle of a [Permutation].
+#[derive(Clone, Debug)]
+pub struct Cycle<'a, const N: usize>
+{
+    permutation: &'a Permutation<N>,
+    start: usize,
+    next: Option<usize>
+}
+
+impl<const N: usize> Iterator for Cycle<'_, N>
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        let i = self.next?;
+        let j = self.permutation.0[i];
+        self.next = if j == self.start {None} else {Some(j)};
+        Some(i)
+    }
+}
+impl<const N: usize> FusedIterator for Cycle<'_, N> {}
+
+#[cfg(test)]
+mod test
+{
+    use crate::Permutation;
+
+    #[test]
+    fn it_works()
+    {
+        let keys = [5, 3, 9, 1, 7, 3, 0, 8];
+        let p = Permutation::argsort(&keys);
+
+        let mut a = keys;
+        p.apply(&mut a);
+        assert_eq!(a, [0, 1, 3, 3, 5, 7, 8, 9]);
+
+        p.apply_inverse(&mut a);
+        assert_eq!(a, keys);
+
+        p.inverse().apply(&mut a);
+        p.apply(&mut a);
+        assert_eq!(a, keys);
+
+        assert_eq!(p.cycles().map(Iterator::count).sum::<usize>(), 8);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,139 @@
+use num_traits::Float;
+
+/// Performs an in-place LU-decomposition with partial pivoting, such that `PA = LU`.
+/// 
+/// The strictly lower triangle is overwritten by `L` (which has an implicit unit diagonal), and the upper triangle by `U`.
+/// 
+/// Returns the row-permutation, and whether or not the permutation is odd.
+pub(crate) fn lu_assign<T, const N: usize>(a: &mut [[T; N]; N]) -> ([usize; N], bool)
+where
+    T: Float
+{
+    let mut p = crate::from_fn(|i| i);
+    let mut odd = false;
+
+    let mut k = 0;
+    while k < N
+    {
+        let mut m = k;
+        let mut max = a[k][k].abs();
+        let mut i = k + 1;
+        while i < N
+        {
+            let x = a[i][k].abs();
+            if x > max
+            {
+                max = x;
+                m = i;
+            }
+            i += 1;
+        }
+        if m != k
+        {
+            a.swap(m, k);
+            p.swap(m, k);
+            odd = !odd;
+        }
+
+        // If the whole column is zero, there is nothing to eliminate
+        if !max.is_zero()
+        {
+            let pivot = a[k][k];
+            let mut i = k + 1;
+            while i < N
+            {
+                let l = a[i][k]/pivot;
+                a[i][k] = l;
+                let mut j = k + 1;
+                while j < N
+                {
+                    a[i][j] = a[i][j] - l*a[k][j];
+                    j += 1;
+                }
+                i += 1;
+            }
+        }
+        k += 1;
+    }
+
+    (p, odd)
+}
+
+/// Checks if the upper or lower triangle of a matrix has a zero on its diagonal, or contains a value that isn't finite.
+/// 
+/// Substitution is well defined otherwise, however the rows are scaled, so no tolerance is applied.
+/// For an LU-decomposition, this is the check on `U`.
+pub(crate) fn triangle_is_singular<T, const N: usize>(a: &[[T; N]; N], upper: bool) -> bool
+where
+    T: Float
+{
+    let mut i = 0;
+    while i < N
+    {
+        let (mut j, end) = if upper {(i, N)} else {(0, i + 1)};
+        while j < end
+        {
+            if !a[i][j].is_finite() || (i == j && a[i][j].is_zero())
+            {
+                return true
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Solves `Ax = b` for `x`, given the LU-decomposition of `A`. The matrix must not be singular.
+pub(crate) fn lu_solve<T, const N: usize>(lu: &[[T; N]; N], p: &[usize; N], b: &[T; N]) -> [T; N]
+where
+    T: Float
+{
+    let mut x: [T; N] = crate::from_fn(|i| b[p[i]]);
+    forward_substitute_assign(lu, &mut x, true);
+    back_substitute_assign(lu, &mut x);
+    x
+}
+
+/// Solves `Lx = b` in-place by forward substitution, reading only the lower triangle of `L`.
+/// 
+/// If `unit_diagonal` is set, the diagonal is assumed to be all ones and is not read. Otherwise, it must not contain zeros.
+pub(crate) fn forward_substitute_assign<T, const N: usize>(l: &[[T; N]; N], x: &mut [T; N], unit_diagonal: bool)
+where
+    T: Float
+{
+    let mut i = 0;
+    while i < N
+    {
+        let mut j = 0;
+        while j < i
+        {
+            x[i] = x[i] - l[i][j]*x[j];
+            j += 1;
+        }
+        if !unit_diagonal
+        {
+            x[i] = x[i]/l[i][i];
+        }
+        i += 1;
+    }
+}
+
+/// Solves `Ux = b` in-place by back substitution, reading only the upper triangle of `U`. The diagonal must not contain zeros.
+pub(crate) fn back_substitute_assign<T, const N: usize>(u: &[[T; N]; N], x: &mut [T; N])
+where
+    T: Float
+{
+    let mut i = N;
+    while i > 0
+    {
+        i -= 1;
+        let mut j = i + 1;
+        while j < N
+        {
+            x[i] = x[i] - u[i][j]*x[j];
+            j += 1;
+        }
+        x[i] = x[i]/u[i][i];
+    }
+}
\ No newline at end of file
@@ -1,6 +1,7 @@
 moddef::moddef!(
     pub(crate) mod {
         guard,
+        lu,
         boxed_array for cfg(feature = "alloc")
     }
 );
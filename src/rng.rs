@@ -0,0 +1,61 @@
+/// A minimal source of uniformly distributed random bits.
+/// 
+/// Implemented for any `FnMut() -> u64`, so any generator can be plugged in with a closure, without depending on a particular crate.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use array__ops::Rng;
+/// 
+/// // A xorshift generator
+/// let mut state = 0x2545f4914f6cdd1du64;
+/// let mut rng = || {
+///     state ^= state << 13;
+///     state ^= state >> 7;
+///     state ^= state << 17;
+///     state
+/// };
+/// 
+/// for _ in 0..100
+/// {
+///     assert!(rng.next_index(6) < 6);
+/// }
+/// ```
+pub trait Rng
+{
+    /// Returns the next 64 random bits.
+    fn next_u64(&mut self) -> u64;
+
+    /// Returns a uniformly distributed index in `0..bound`, without modulo bias.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `bound` is zero.
+    fn next_index(&mut self, bound: usize) -> usize
+    {
+        assert!(bound != 0, "bound must be non-zero");
+
+        // Lemire's nearly divisionless method
+        let bound = bound as u64;
+        let mut m = self.next_u64() as u128*bound as u128;
+        if (m as u64) < bound
+        {
+            let threshold = bound.wrapping_neg() % bound;
+            while (m as u64) < threshold
+            {
+                m = self.next_u64() as u128*bound as u128;
+            }
+        }
+        (m >> 64) as usize
+    }
+}
+
+impl<F> Rng for F
+where
+    F: FnMut() -> u64
+{
+    fn next_u64(&mut self) -> u64
+    {
+        self()
+    }
+}
\ No newline at end of file